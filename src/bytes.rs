@@ -0,0 +1,40 @@
+//! Module for zero-copy byte serialization of math types for GPU upload
+//!
+//! Mirrors the `AsBytes` pattern engines like Bevy use to push vertex/uniform
+//! data into GPU buffers without manual field shuffling.
+
+/// Types that can be written into a byte buffer as little-endian bytes,
+/// suitable for a `memcpy` into a vertex/uniform buffer.
+///
+/// `CartesianVector` implements this by encoding each field individually
+/// with `to_le_bytes`. The `#[repr(C)]` types (`Vector2D`, `Vector3D`,
+/// `Matrix3x3`, `Matrix4`) implement it via [`copy_repr_c_bytes`] instead,
+/// which trusts the type's native in-memory layout and copies its bytes
+/// as-is rather than writing each field individually — the approach Bevy
+/// settled on after dropping its `zerocopy` dependency. Either path produces
+/// the same little-endian contract, so generic GPU-upload code can be
+/// written against `AsBytes` alone regardless of which a type uses.
+pub trait AsBytes {
+    /// Writes this value's components into `buffer` as little-endian bytes.
+    fn write_bytes(&self, buffer: &mut [u8]);
+
+    /// Returns the number of bytes `write_bytes` writes.
+    fn byte_len(&self) -> usize;
+}
+
+/// Copies `value`'s raw bytes into `buffer` as tightly-packed little-endian
+/// `f64`s. Safe to call on any `#[repr(C)]` type made up entirely of `f64`
+/// fields, since such a type has no padding and every byte of its
+/// representation is meaningful; on a big-endian host each 8-byte `f64` lane
+/// is reversed in place after the copy so the output matches [`AsBytes`]'s
+/// little-endian contract regardless of the host's native endianness.
+pub(crate) fn copy_repr_c_bytes<T>(value: &T, buffer: &mut [u8]) {
+    let size = std::mem::size_of::<T>();
+    let src = unsafe { std::slice::from_raw_parts(value as *const T as *const u8, size) };
+    buffer[..size].copy_from_slice(src);
+    if cfg!(target_endian = "big") {
+        for lane in buffer[..size].chunks_exact_mut(8) {
+            lane.reverse();
+        }
+    }
+}
@@ -11,5 +11,7 @@
 //! let v3 = v1.cross(v2);
 //! println!("{:?}", v3);
 //! ```
+pub mod bytes;
 pub mod matrices;
+pub mod quaternions;
 pub mod vectors;
@@ -1,4 +1,21 @@
 //!  Module used to perform basic opertations on matrices of arbitrary dimensions
+
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when an LU-based operation encounters a pivot below the
+/// singularity tolerance.
+#[derive(Debug, PartialEq)]
+pub struct SingularMatrix;
+
+impl fmt::Display for SingularMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "matrix is singular (pivot below tolerance)")
+    }
+}
+
+impl Error for SingularMatrix {}
+
 /// Adds two matrices of arbitrary dimensions
 /// # Example
 /// ## Code
@@ -184,7 +201,69 @@ pub fn identity(size: usize) -> Vec<Vec<f64>> {
     result
 }
 
+/// Factors a square matrix into combined L/U form using Gaussian elimination
+/// with partial pivoting, or `None` if the matrix is singular
+///
+/// Returns `(lu, perm, sign)` where `lu` packs the unit-diagonal lower
+/// triangle below the diagonal and the upper triangle on and above it,
+/// `perm` records which original row ended up in each position, and `sign`
+/// is `+1.0`/`-1.0` depending on the number of row swaps performed. This is
+/// the O(n³) factorization that `determinant`, `solve`, and `inverse` can
+/// reuse instead of repeating elimination from scratch.
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::functions::lu_decompose;
+///
+/// fn main() {
+///     let a = vec![vec![4.0, 3.0], vec![6.0, 3.0]];
+///     let result = lu_decompose(a);
+///     println!("{:?}", result);
+/// }
+/// ```
+pub fn lu_decompose(a: Vec<Vec<f64>>) -> Option<(Vec<Vec<f64>>, Vec<usize>, f64)> {
+    const EPSILON: f64 = 1e-12;
+    let n = a.len();
+    let mut lu = a;
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut sign = 1.0;
+
+    for k in 0..n {
+        let mut pivot_row = k;
+        let mut pivot_value = lu[k][k].abs();
+        for (row, candidate) in lu.iter().enumerate().take(n).skip(k + 1) {
+            if candidate[k].abs() > pivot_value {
+                pivot_row = row;
+                pivot_value = candidate[k].abs();
+            }
+        }
+        if pivot_value < EPSILON {
+            return None;
+        }
+        if pivot_row != k {
+            lu.swap(k, pivot_row);
+            perm.swap(k, pivot_row);
+            sign = -sign;
+        }
+
+        let (pivot_rows, lower_rows) = lu.split_at_mut(k + 1);
+        let pivot_row = &pivot_rows[k];
+        for row in lower_rows {
+            let factor = row[k] / pivot_row[k];
+            row[k] = factor;
+            for (entry, pivot_entry) in row.iter_mut().zip(pivot_row.iter()).skip(k + 1) {
+                *entry -= factor * pivot_entry;
+            }
+        }
+    }
+
+    Some((lu, perm, sign))
+}
+
 /// Calculates the determinant of a square matrix
+///
+/// Built on `lu_decompose`, so this runs in O(n³) instead of the O(n!) of a
+/// cofactor expansion.
 /// # Example
 /// ## Code
 /// ```rust
@@ -202,28 +281,192 @@ pub fn identity(size: usize) -> Vec<Vec<f64>> {
 /// -2.0
 /// ```
 pub fn determinant(a: Vec<Vec<f64>>) -> f64 {
-    if a.len() == 2 {
-        return a[0][0] * a[1][1] - a[0][1] * a[1][0];
-    }
-    let mut result: f64 = 0.0;
-    for i in 0..a.len() {
-        let mut sub_matrix: Vec<Vec<f64>> = Vec::new();
-        for j in 1..a.len() {
-            let mut row: Vec<f64> = Vec::new();
-            for k in 0..a.len() {
-                if k != i {
-                    row.push(a[j][k]);
-                }
+    match lu_decompose(a) {
+        None => 0.0,
+        Some((lu, _perm, sign)) => {
+            let mut result = sign;
+            for (i, row) in lu.iter().enumerate() {
+                result *= row[i];
             }
-            sub_matrix.push(row);
+            result
+        }
+    }
+}
+
+/// Calculates the determinant of a square matrix via `lu_decompose`,
+/// flagging singular matrices with [`SingularMatrix`] instead of collapsing
+/// them to `0.0`.
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::functions::determinant_lu;
+///
+/// fn main() {
+///     let a = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+///     let result = determinant_lu(a);
+///     println!("{:?}", result);
+/// }
+/// ```
+/// ## Terminal
+/// ```bash
+/// $ cargo run
+/// Ok(-2.0)
+/// ```
+pub fn determinant_lu(a: Vec<Vec<f64>>) -> Result<f64, SingularMatrix> {
+    let (lu, _perm, sign) = lu_decompose(a).ok_or(SingularMatrix)?;
+    let mut result = sign;
+    for (i, row) in lu.iter().enumerate() {
+        result *= row[i];
+    }
+    Ok(result)
+}
+
+/// Solves the linear system `Ax = b`, or `None` if `a` is singular (a pivot
+/// falls below the tolerance `lu_decompose` enforces)
+///
+/// Reuses the `lu_decompose` factorization: the row permutation is applied to
+/// `b`, then forward substitution solves `Ly = Pb` against the unit-lower
+/// triangle followed by back substitution solving `Ux = y` against the upper
+/// triangle. This is both cheaper and numerically better behaved than
+/// computing `inverse(a)` and multiplying it by `b`.
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::functions::solve;
+///
+/// fn main() {
+///     let a = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+///     let b = vec![1.0, 0.0];
+///     let result = solve(a, b);
+///     println!("{:?}", result);
+/// }
+/// ```
+/// ## Terminal
+/// ```bash
+/// $ cargo run
+/// Some([0.6000000000000001, -0.2])
+/// ```
+pub fn solve(a: Vec<Vec<f64>>, b: Vec<f64>) -> Option<Vec<f64>> {
+    try_solve(a, b).ok()
+}
+
+/// Solves the linear system `Ax = b`, or returns [`SingularMatrix`] if `a`
+/// is singular. Like [`solve`], but flags singularity with an error instead
+/// of collapsing it to `None`.
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::functions::try_solve;
+///
+/// fn main() {
+///     let a = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+///     let b = vec![1.0, 0.0];
+///     let result = try_solve(a, b);
+///     println!("{:?}", result);
+/// }
+/// ```
+/// ## Terminal
+/// ```bash
+/// $ cargo run
+/// Ok([0.6000000000000001, -0.2])
+/// ```
+pub fn try_solve(a: Vec<Vec<f64>>, b: Vec<f64>) -> Result<Vec<f64>, SingularMatrix> {
+    let (lu, perm, _sign) = lu_decompose(a).ok_or(SingularMatrix)?;
+    Ok(solve_lu(&lu, &perm, &b))
+}
+
+/// Solves `Ax = b` against an already-factored `(lu, perm)` pair from
+/// `lu_decompose`, via forward substitution solving `Ly = Pb` followed by
+/// back substitution solving `Ux = y`. Factored out of `solve` so `inverse`
+/// can reuse a single factorization across every identity column instead of
+/// re-decomposing `a` from scratch per column.
+fn solve_lu(lu: &[Vec<f64>], perm: &[usize], b: &[f64]) -> Vec<f64> {
+    let n = lu.len();
+
+    let mut y = vec![0.0; n];
+    for i in 0..n {
+        let mut sum = b[perm[i]];
+        for j in 0..i {
+            sum -= lu[i][j] * y[j];
         }
-        if i % 2 == 0 {
-            result += a[0][i] * determinant(sub_matrix);
-        } else {
-            result -= a[0][i] * determinant(sub_matrix);
+        y[i] = sum;
+    }
+
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let mut sum = y[i];
+        for j in (i + 1)..n {
+            sum -= lu[i][j] * x[j];
         }
+        x[i] = sum / lu[i][i];
     }
-    result
+
+    x
+}
+
+/// Calculates the inverse of a square matrix, or `None` if it is singular (a
+/// pivot falls below the tolerance `lu_decompose` enforces)
+///
+/// Factors `a` once via `lu_decompose`, then builds each column of the
+/// inverse by solving `A x = e_i` against the standard basis vector `e_i`
+/// with `solve_lu`. This is both cheaper and more numerically stable than
+/// the cofactor/adjugate approach, since the O(n³) factorization is shared
+/// across all `n` solves instead of repeating elimination per column.
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::functions::inverse;
+///
+/// fn main() {
+///     let a = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+///     let result = inverse(a);
+///     println!("{:?}", result);
+/// }
+/// ```
+/// ## Terminal
+/// ```bash
+/// $ cargo run
+/// Some([[0.6000000000000001, -0.7000000000000001], [-0.2, 0.4]])
+/// ```
+pub fn inverse(a: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    try_inverse(a).ok()
+}
+
+/// Calculates the inverse of a square matrix, or returns [`SingularMatrix`]
+/// if it is singular. Like [`inverse`], but flags singularity with an error
+/// instead of collapsing it to `None`.
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::functions::try_inverse;
+///
+/// fn main() {
+///     let a = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+///     let result = try_inverse(a);
+///     println!("{:?}", result);
+/// }
+/// ```
+/// ## Terminal
+/// ```bash
+/// $ cargo run
+/// Ok([[0.6000000000000001, -0.7000000000000001], [-0.2, 0.4]])
+/// ```
+pub fn try_inverse(a: Vec<Vec<f64>>) -> Result<Vec<Vec<f64>>, SingularMatrix> {
+    let n = a.len();
+    let (lu, perm, _sign) = lu_decompose(a).ok_or(SingularMatrix)?;
+
+    let mut columns: Vec<Vec<f64>> = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut e_i = vec![0.0; n];
+        e_i[i] = 1.0;
+        columns.push(solve_lu(&lu, &perm, &e_i));
+    }
+
+    let mut result: Vec<Vec<f64>> = Vec::with_capacity(n);
+    for row in 0..n {
+        result.push(columns.iter().map(|col| col[row]).collect());
+    }
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -287,4 +530,114 @@ mod tests {
         let result = determinant(a);
         assert_eq!(result, -2.0);
     }
+
+    #[test]
+    fn test_determinant_singular() {
+        let a = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+        assert_eq!(determinant(a), 0.0);
+    }
+
+    #[test]
+    fn test_lu_decompose() {
+        let a = vec![vec![4.0, 3.0], vec![6.0, 3.0]];
+        let (lu, perm, sign) = lu_decompose(a).unwrap();
+        assert_eq!(perm, vec![1, 0]);
+        assert_eq!(sign, -1.0);
+        assert_eq!(lu[0][0], 6.0);
+        assert_eq!(lu[0][1], 3.0);
+        assert_eq!(lu[1][1], 1.0);
+    }
+
+    #[test]
+    fn test_lu_decompose_singular() {
+        let a = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+        assert_eq!(lu_decompose(a), None);
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+        let b = vec![1.0, 0.0];
+        let result = solve(a, b).unwrap();
+        assert!((result[0] - 0.6).abs() < 1e-9);
+        assert!((result[1] - -0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_solve_singular() {
+        let a = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+        let b = vec![1.0, 2.0, 3.0];
+        assert_eq!(solve(a, b), None);
+    }
+
+    #[test]
+    fn test_try_solve_singular() {
+        let a = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+        let b = vec![1.0, 2.0, 3.0];
+        assert_eq!(try_solve(a, b), Err(SingularMatrix));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+        let result = inverse(a).unwrap();
+        let expected = [vec![0.6, -0.7], vec![-0.2, 0.4]];
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((result[i][j] - expected[i][j]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let a = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+        assert_eq!(inverse(a), None);
+    }
+
+    #[test]
+    fn test_try_inverse_singular() {
+        let a = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+        assert_eq!(try_inverse(a), Err(SingularMatrix));
+    }
+
+    #[test]
+    fn test_determinant_lu() {
+        let a = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        assert_eq!(determinant_lu(a), Ok(-2.0));
+    }
+
+    #[test]
+    fn test_determinant_lu_singular() {
+        let a = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+        assert_eq!(determinant_lu(a), Err(SingularMatrix));
+    }
 }
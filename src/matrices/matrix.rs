@@ -0,0 +1,143 @@
+//! Module for a dynamically-sized, column-major dense matrix
+//!
+//! Unlike the `Vec<Vec<f64>>` free functions in [`super::functions`], which
+//! jagged-allocate one `Vec` per row, [`Matrix`] stores every element in a
+//! single flat `Vec<f64>` laid out column-major, mirroring the nalgebra
+//! convention. This gives contiguous storage suitable for linear indexing
+//! and for handing a slice straight to FFI/BLAS code.
+
+/// A dense matrix backed by a single flat `Vec<f64>` in column-major order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// Builds a matrix from a row-major slice (the conventional way to write
+    /// a literal matrix), transposing it into the struct's column-major
+    /// storage.
+    /// # Example
+    /// ## Code
+    /// ```rust
+    /// use linear_algebra::matrices::matrix::Matrix;
+    ///
+    /// fn main() {
+    ///     let m = Matrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    ///     println!("{:?}", m.as_slice());
+    /// }
+    /// ```
+    /// ## Terminal
+    /// ```bash
+    /// $ cargo run
+    /// [1.0, 3.0, 2.0, 4.0]
+    /// ```
+    pub fn from_row_slice(rows: usize, cols: usize, data: &[f64]) -> Matrix {
+        let mut column_major = vec![0.0; rows * cols];
+        for r in 0..rows {
+            for c in 0..cols {
+                column_major[c * rows + r] = data[r * cols + c];
+            }
+        }
+        Matrix {
+            rows,
+            cols,
+            data: column_major,
+        }
+    }
+
+    /// Builds a matrix directly from a column-major slice, matching the
+    /// struct's native storage order.
+    /// # Example
+    /// ## Code
+    /// ```rust
+    /// use linear_algebra::matrices::matrix::Matrix;
+    ///
+    /// fn main() {
+    ///     let m = Matrix::from_column_slice(2, 2, &[1.0, 3.0, 2.0, 4.0]);
+    ///     println!("{:?}", m.as_slice());
+    /// }
+    /// ```
+    /// ## Terminal
+    /// ```bash
+    /// $ cargo run
+    /// [1.0, 3.0, 2.0, 4.0]
+    /// ```
+    pub fn from_column_slice(rows: usize, cols: usize, data: &[f64]) -> Matrix {
+        Matrix {
+            rows,
+            cols,
+            data: data.to_vec(),
+        }
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the backing storage as a contiguous column-major slice,
+    /// suitable for handing to FFI or BLAS bindings.
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Returns the elements of row `i` as a new vector.
+    pub fn row(&self, i: usize) -> Vec<f64> {
+        (0..self.cols).map(|c| self.data[c * self.rows + i]).collect()
+    }
+
+    /// Returns the elements of column `j` as a new vector.
+    pub fn column(&self, j: usize) -> Vec<f64> {
+        self.data[j * self.rows..(j + 1) * self.rows].to_vec()
+    }
+
+    /// Returns an iterator over the elements in column-major storage order.
+    pub fn iter(&self) -> std::slice::Iter<'_, f64> {
+        self.data.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_row_slice() {
+        let m = Matrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(m.as_slice(), &[1.0, 3.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_from_column_slice() {
+        let m = Matrix::from_column_slice(2, 2, &[1.0, 3.0, 2.0, 4.0]);
+        assert_eq!(m.as_slice(), &[1.0, 3.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_row() {
+        let m = Matrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(m.row(0), vec![1.0, 2.0]);
+        assert_eq!(m.row(1), vec![3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_column() {
+        let m = Matrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(m.column(0), vec![1.0, 3.0]);
+        assert_eq!(m.column(1), vec![2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let m = Matrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+        let collected: Vec<f64> = m.iter().copied().collect();
+        assert_eq!(collected, vec![1.0, 3.0, 2.0, 4.0]);
+    }
+}
@@ -0,0 +1,145 @@
+//! Module for the column-based `Matrix3` rotation/transform type
+
+use crate::vectors::CartesianVector;
+
+/// A 3x3 matrix stored as three column `CartesianVector`s, mirroring the
+/// constructors cgmath provides for building and reusing a single
+/// rotation/transform instead of recomputing trig per vector.
+#[derive(Copy, Clone, Debug)]
+pub struct Matrix3 {
+    col0: CartesianVector,
+    col1: CartesianVector,
+    col2: CartesianVector,
+}
+
+impl Matrix3 {
+    /// Creates a new Matrix3 from its three columns.
+    pub fn new(col0: CartesianVector, col1: CartesianVector, col2: CartesianVector) -> Matrix3 {
+        Matrix3 { col0, col1, col2 }
+    }
+
+    /// Returns the column at the given index.
+    pub fn column(&self, index: usize) -> CartesianVector {
+        match index {
+            0 => self.col0,
+            1 => self.col1,
+            2 => self.col2,
+            _ => panic!("Invalid column index"),
+        }
+    }
+
+    /// Returns the row at the given index.
+    pub fn row(&self, index: usize) -> CartesianVector {
+        match index {
+            0 => CartesianVector::new(self.col0.x(), self.col1.x(), self.col2.x()),
+            1 => CartesianVector::new(self.col0.y(), self.col1.y(), self.col2.y()),
+            2 => CartesianVector::new(self.col0.z(), self.col1.z(), self.col2.z()),
+            _ => panic!("Invalid row index"),
+        }
+    }
+
+    /// Builds the rotation matrix for `angle` radians about `axis`, using
+    /// the same Rodrigues coefficients `CartesianVector::rotate_about_axis`
+    /// inlines, factored here into a reusable matrix.
+    pub fn from_axis_angle(axis: CartesianVector, angle: f64) -> Matrix3 {
+        let axis = axis.normalize();
+        let c = angle.cos();
+        let s = angle.sin();
+        let t = 1.0 - c;
+        let (x, y, z) = (axis.x(), axis.y(), axis.z());
+        Matrix3 {
+            col0: CartesianVector::new(c + x * x * t, y * x * t + z * s, z * x * t - y * s),
+            col1: CartesianVector::new(x * y * t - z * s, c + y * y * t, z * y * t + x * s),
+            col2: CartesianVector::new(x * z * t + y * s, y * z * t - x * s, c + z * z * t),
+        }
+    }
+
+    /// Builds an orthonormal basis matrix from a view `dir`ection and an
+    /// `up` hint: `side = up.cross(dir).normalize()`, then
+    /// `up' = dir.cross(side)`, packed as the matrix's rows.
+    pub fn look_at(dir: CartesianVector, up: CartesianVector) -> Matrix3 {
+        let side = up.cross(dir).normalize();
+        let up = dir.cross(side);
+        Matrix3 {
+            col0: CartesianVector::new(side.x(), up.x(), dir.x()),
+            col1: CartesianVector::new(side.y(), up.y(), dir.y()),
+            col2: CartesianVector::new(side.z(), up.z(), dir.z()),
+        }
+    }
+
+    /// Multiplies this matrix by a `CartesianVector`.
+    pub fn multiply_vector(&self, v: CartesianVector) -> CartesianVector {
+        self.col0 * v.x() + self.col1 * v.y() + self.col2 * v.z()
+    }
+
+    /// Multiplies this matrix by another Matrix3.
+    pub fn multiply_matrix(&self, other: &Matrix3) -> Matrix3 {
+        Matrix3 {
+            col0: self.multiply_vector(other.col0),
+            col1: self.multiply_vector(other.col1),
+            col2: self.multiply_vector(other.col2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_row_and_column() {
+        let m = Matrix3::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(m.column(0), CartesianVector::new(1.0, 2.0, 3.0));
+        assert_eq!(m.row(0), CartesianVector::new(1.0, 4.0, 7.0));
+    }
+
+    #[test]
+    fn test_from_axis_angle_matches_rotate_about_axis() {
+        let axis = CartesianVector::new(1.0, 1.0, 1.0).normalize();
+        let angle = std::f64::consts::PI / 2.0;
+        let v = CartesianVector::new(1.0, 2.0, 3.0);
+
+        let matrix = Matrix3::from_axis_angle(axis, angle);
+        let expected = v.rotate_about_axis(axis, angle);
+        let actual = matrix.multiply_vector(v);
+
+        assert_approx_eq!(actual.x(), expected.x(), 1e-12);
+        assert_approx_eq!(actual.y(), expected.y(), 1e-12);
+        assert_approx_eq!(actual.z(), expected.z(), 1e-12);
+    }
+
+    #[test]
+    fn test_look_at_is_orthonormal() {
+        let dir = CartesianVector::new(0.0, 0.0, -1.0);
+        let up = CartesianVector::y_axis();
+        let matrix = Matrix3::look_at(dir, up);
+
+        assert_approx_eq!(matrix.row(0).magnitude(), 1.0, 1e-12);
+        assert_approx_eq!(matrix.row(1).magnitude(), 1.0, 1e-12);
+        assert_approx_eq!(matrix.row(2).magnitude(), 1.0, 1e-12);
+        assert_approx_eq!(matrix.row(0).dot(matrix.row(1)), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_multiply_matrix() {
+        let identity = Matrix3::new(
+            CartesianVector::x_axis(),
+            CartesianVector::y_axis(),
+            CartesianVector::z_axis(),
+        );
+        let m = Matrix3::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        let result = identity.multiply_matrix(&m);
+        assert_eq!(result.column(0), m.column(0));
+        assert_eq!(result.column(1), m.column(1));
+        assert_eq!(result.column(2), m.column(2));
+    }
+}
@@ -1,7 +1,10 @@
+use crate::bytes::{copy_repr_c_bytes, AsBytes};
 use crate::vectors::vector3d::Vector3D;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Represents a 3x3 matrix.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
 pub struct Matrix3x3 {
     rows: [Vector3D; 3],
 }
@@ -28,6 +31,24 @@ impl Matrix3x3 {
         )
     }
 
+    /// Builds the rotation matrix for `angle_rad` radians about `axis`, via
+    /// the Rodrigues formula: normalize `axis` to `(x, y, z)`, let
+    /// `c = cos(angle)`, `s = sin(angle)`, `t = 1 - c`, and fill in the rows
+    /// `[t*x*x+c, t*x*y-s*z, t*x*z+s*y]`, `[t*x*y+s*z, t*y*y+c, t*y*z-s*x]`,
+    /// `[t*x*z-s*y, t*y*z+s*x, t*z*z+c]`.
+    pub fn rotation_from_axis_angle(axis: Vector3D, angle_rad: f64) -> Matrix3x3 {
+        let axis = axis * (1.0 / axis.magnitude());
+        let (x, y, z) = (axis.element(0), axis.element(1), axis.element(2));
+        let c = angle_rad.cos();
+        let s = angle_rad.sin();
+        let t = 1.0 - c;
+        Matrix3x3::new(
+            Vector3D::new(t * x * x + c, t * x * y - s * z, t * x * z + s * y),
+            Vector3D::new(t * x * y + s * z, t * y * y + c, t * y * z - s * x),
+            Vector3D::new(t * x * z - s * y, t * y * z + s * x, t * z * z + c),
+        )
+    }
+
     /// Calculates the determinant of the matrix.
     pub fn determinant(&self) -> f64 {
         self.rows[0].element(0)
@@ -47,34 +68,148 @@ impl Matrix3x3 {
     }
 
     /// Returns the sum of this matrix and another matrix.
+    ///
+    /// Thin wrapper over `Add` kept for backwards compatibility; prefer `self + other`.
     pub fn plus(&self, other: &Matrix3x3) -> Matrix3x3 {
-        Matrix3x3::new(
-            self.row(0).plus(&other.row(0)),
-            self.row(1).plus(&other.row(1)),
-            self.row(2).plus(&other.row(2)),
-        )
+        self + other
     }
 
     /// Returns the difference between this matrix and another matrix.
+    ///
+    /// Thin wrapper over `Sub` kept for backwards compatibility; prefer `self - other`.
     pub fn minus(&self, other: &Matrix3x3) -> Matrix3x3 {
-        Matrix3x3::new(
-            self.row(0).minus(&other.row(0)),
-            self.row(1).minus(&other.row(1)),
-            self.row(2).minus(&other.row(2)),
-        )
+        self - other
     }
 
     /// Returns the product of this matrix and a scalar.
+    ///
+    /// Thin wrapper over `Mul<f64>` kept for backwards compatibility; prefer `self * scalar`.
     pub fn times_scalar(&self, scalar: f64) -> Matrix3x3 {
-        Matrix3x3::new(
-            self.row(0).times(scalar),
-            self.row(1).times(scalar),
-            self.row(2).times(scalar),
-        )
+        self * scalar
     }
 
     /// Returns the product of this matrix and another 3x3 matrix.
+    ///
+    /// Thin wrapper over `Mul<&Matrix3x3>` kept for backwards compatibility; prefer `self * other`.
     pub fn times_matrix3x3(&self, other: &Matrix3x3) -> Matrix3x3 {
+        self * other
+    }
+}
+
+impl Add<&Matrix3x3> for &Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn add(self, other: &Matrix3x3) -> Matrix3x3 {
+        Matrix3x3::new(
+            self.row(0) + other.row(0),
+            self.row(1) + other.row(1),
+            self.row(2) + other.row(2),
+        )
+    }
+}
+
+impl Add<Matrix3x3> for Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn add(self, other: Matrix3x3) -> Matrix3x3 {
+        &self + &other
+    }
+}
+
+impl Add<&Matrix3x3> for Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn add(self, other: &Matrix3x3) -> Matrix3x3 {
+        &self + other
+    }
+}
+
+impl Add<Matrix3x3> for &Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn add(self, other: Matrix3x3) -> Matrix3x3 {
+        self + &other
+    }
+}
+
+impl AddAssign for Matrix3x3 {
+    fn add_assign(&mut self, other: Matrix3x3) {
+        *self = &*self + &other;
+    }
+}
+
+impl Sub<&Matrix3x3> for &Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn sub(self, other: &Matrix3x3) -> Matrix3x3 {
+        Matrix3x3::new(
+            self.row(0) - other.row(0),
+            self.row(1) - other.row(1),
+            self.row(2) - other.row(2),
+        )
+    }
+}
+
+impl Sub<Matrix3x3> for Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn sub(self, other: Matrix3x3) -> Matrix3x3 {
+        &self - &other
+    }
+}
+
+impl Sub<&Matrix3x3> for Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn sub(self, other: &Matrix3x3) -> Matrix3x3 {
+        &self - other
+    }
+}
+
+impl Sub<Matrix3x3> for &Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn sub(self, other: Matrix3x3) -> Matrix3x3 {
+        self - &other
+    }
+}
+
+impl SubAssign for Matrix3x3 {
+    fn sub_assign(&mut self, other: Matrix3x3) {
+        *self = &*self - &other;
+    }
+}
+
+impl Mul<f64> for &Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn mul(self, scalar: f64) -> Matrix3x3 {
+        Matrix3x3::new(
+            self.row(0) * scalar,
+            self.row(1) * scalar,
+            self.row(2) * scalar,
+        )
+    }
+}
+
+impl Mul<f64> for Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn mul(self, scalar: f64) -> Matrix3x3 {
+        &self * scalar
+    }
+}
+
+impl MulAssign<f64> for Matrix3x3 {
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = &*self * scalar;
+    }
+}
+
+impl Mul<&Matrix3x3> for &Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn mul(self, other: &Matrix3x3) -> Matrix3x3 {
         let row1 = Vector3D::new(
             self.row(0).dot(&other.column(0)),
             self.row(0).dot(&other.column(1)),
@@ -95,6 +230,96 @@ impl Matrix3x3 {
     }
 }
 
+impl Mul<Matrix3x3> for Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn mul(self, other: Matrix3x3) -> Matrix3x3 {
+        &self * &other
+    }
+}
+
+impl Mul<&Matrix3x3> for Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn mul(self, other: &Matrix3x3) -> Matrix3x3 {
+        &self * other
+    }
+}
+
+impl Mul<Matrix3x3> for &Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn mul(self, other: Matrix3x3) -> Matrix3x3 {
+        self * &other
+    }
+}
+
+impl Mul<&Vector3D> for &Matrix3x3 {
+    type Output = Vector3D;
+
+    fn mul(self, other: &Vector3D) -> Vector3D {
+        Vector3D::new(
+            self.row(0).dot(other),
+            self.row(1).dot(other),
+            self.row(2).dot(other),
+        )
+    }
+}
+
+impl Mul<Vector3D> for Matrix3x3 {
+    type Output = Vector3D;
+
+    fn mul(self, other: Vector3D) -> Vector3D {
+        &self * &other
+    }
+}
+
+impl Mul<&Vector3D> for Matrix3x3 {
+    type Output = Vector3D;
+
+    fn mul(self, other: &Vector3D) -> Vector3D {
+        &self * other
+    }
+}
+
+impl Mul<Vector3D> for &Matrix3x3 {
+    type Output = Vector3D;
+
+    // Dropping the `&` here would re-resolve to this same impl (self: &Matrix3x3,
+    // other: Vector3D) instead of `Mul<&Vector3D> for &Matrix3x3`, causing
+    // infinite recursion, so clippy's op_ref suggestion doesn't apply.
+    #[allow(clippy::op_ref)]
+    fn mul(self, other: Vector3D) -> Vector3D {
+        self * &other
+    }
+}
+
+impl AsBytes for Matrix3x3 {
+    fn write_bytes(&self, buffer: &mut [u8]) {
+        copy_repr_c_bytes(self, buffer);
+    }
+
+    fn byte_len(&self) -> usize {
+        std::mem::size_of::<Matrix3x3>()
+    }
+}
+
+impl Neg for &Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn neg(self) -> Matrix3x3 {
+        Matrix3x3::new(-&self.row(0), -&self.row(1), -&self.row(2))
+    }
+}
+
+impl Neg for Matrix3x3 {
+    type Output = Matrix3x3;
+
+    fn neg(self) -> Matrix3x3 {
+        -&self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,6 +349,18 @@ mod tests {
         assert_eq!(matrix.column(2), Vector3D::new(3.0, 6.0, 9.0));
     }
 
+    #[test]
+    fn test_rotation_from_axis_angle() {
+        let matrix = Matrix3x3::rotation_from_axis_angle(
+            Vector3D::new(0.0, 0.0, 1.0),
+            std::f64::consts::PI / 2.0,
+        );
+        let rotated = matrix * Vector3D::new(1.0, 0.0, 0.0);
+        assert_approx_eq!(rotated.element(0), 0.0, 1e-12);
+        assert_approx_eq!(rotated.element(1), 1.0, 1e-12);
+        assert_approx_eq!(rotated.element(2), 0.0, 1e-12);
+    }
+
     #[test]
     fn test_determinant() {
         let row1 = Vector3D::new(1.0, 2.0, 3.0);
@@ -228,4 +465,109 @@ mod tests {
 
         assert_eq!(matrix1.times_matrix3x3(&matrix2), expected);
     }
+
+    #[test]
+    fn test_mul_operator_matrix_vector() {
+        let row1 = Vector3D::new(1.0, 2.0, 3.0);
+        let row2 = Vector3D::new(4.0, 5.0, 6.0);
+        let row3 = Vector3D::new(7.0, 8.0, 9.0);
+        let matrix = Matrix3x3::new(row1, row2, row3);
+        let vector = Vector3D::new(1.0, 2.0, 3.0);
+
+        let expected = Vector3D::new(14.0, 32.0, 50.0);
+
+        assert_eq!(matrix * vector, expected);
+    }
+
+    #[test]
+    fn test_neg_operator() {
+        let row1 = Vector3D::new(1.0, 2.0, 3.0);
+        let row2 = Vector3D::new(4.0, 5.0, 6.0);
+        let row3 = Vector3D::new(7.0, 8.0, 9.0);
+        let matrix = Matrix3x3::new(row1, row2, row3);
+
+        let expected = Matrix3x3::new(
+            Vector3D::new(-1.0, -2.0, -3.0),
+            Vector3D::new(-4.0, -5.0, -6.0),
+            Vector3D::new(-7.0, -8.0, -9.0),
+        );
+
+        assert_eq!(-&matrix, expected);
+    }
+
+    #[test]
+    fn test_add_assign_operator() {
+        let mut matrix = Matrix3x3::new(
+            Vector3D::new(1.0, 2.0, 3.0),
+            Vector3D::new(4.0, 5.0, 6.0),
+            Vector3D::new(7.0, 8.0, 9.0),
+        );
+        matrix += Matrix3x3::new(
+            Vector3D::new(10.0, 11.0, 12.0),
+            Vector3D::new(13.0, 14.0, 15.0),
+            Vector3D::new(16.0, 17.0, 18.0),
+        );
+        assert_eq!(
+            matrix,
+            Matrix3x3::new(
+                Vector3D::new(11.0, 13.0, 15.0),
+                Vector3D::new(17.0, 19.0, 21.0),
+                Vector3D::new(23.0, 25.0, 27.0),
+            )
+        );
+    }
+
+    #[test]
+    fn test_sub_assign_operator() {
+        let mut matrix = Matrix3x3::new(
+            Vector3D::new(10.0, 11.0, 12.0),
+            Vector3D::new(13.0, 14.0, 15.0),
+            Vector3D::new(16.0, 17.0, 18.0),
+        );
+        matrix -= Matrix3x3::new(
+            Vector3D::new(1.0, 2.0, 3.0),
+            Vector3D::new(4.0, 5.0, 6.0),
+            Vector3D::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(
+            matrix,
+            Matrix3x3::new(
+                Vector3D::new(9.0, 9.0, 9.0),
+                Vector3D::new(9.0, 9.0, 9.0),
+                Vector3D::new(9.0, 9.0, 9.0),
+            )
+        );
+    }
+
+    #[test]
+    fn test_mul_assign_operator() {
+        let mut matrix = Matrix3x3::new(
+            Vector3D::new(1.0, 2.0, 3.0),
+            Vector3D::new(4.0, 5.0, 6.0),
+            Vector3D::new(7.0, 8.0, 9.0),
+        );
+        matrix *= 2.0;
+        assert_eq!(
+            matrix,
+            Matrix3x3::new(
+                Vector3D::new(2.0, 4.0, 6.0),
+                Vector3D::new(8.0, 10.0, 12.0),
+                Vector3D::new(14.0, 16.0, 18.0),
+            )
+        );
+    }
+
+    #[test]
+    fn test_bytes_write_bytes() {
+        let matrix = Matrix3x3::new(
+            Vector3D::new(1.0, 2.0, 3.0),
+            Vector3D::new(4.0, 5.0, 6.0),
+            Vector3D::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(matrix.byte_len(), 72);
+        let mut buffer = [0u8; 72];
+        matrix.write_bytes(&mut buffer);
+        assert_eq!(&buffer[0..8], &1.0f64.to_le_bytes());
+        assert_eq!(&buffer[64..72], &9.0f64.to_le_bytes());
+    }
 }
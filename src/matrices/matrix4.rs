@@ -0,0 +1,309 @@
+//! Module for the fixed-size `Matrix4` homogeneous transform type
+
+use crate::bytes::{copy_repr_c_bytes, AsBytes};
+use crate::vectors::CartesianVector;
+use std::ops::Mul;
+
+/// A 4x4 matrix stored row-major as `[[f64; 4]; 4]`, used to build and reuse
+/// a single homogeneous transform instead of recomputing one per vector.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Matrix4 {
+    rows: [[f64; 4]; 4],
+}
+
+impl Matrix4 {
+    /// Creates a new Matrix4 from its row-major elements.
+    pub fn new(rows: [[f64; 4]; 4]) -> Matrix4 {
+        Matrix4 { rows }
+    }
+
+    /// Returns the 4x4 identity matrix.
+    pub fn identity() -> Matrix4 {
+        Matrix4 {
+            rows: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Builds a homogeneous translation matrix that moves a point by `v`.
+    pub fn from_translation(v: CartesianVector) -> Matrix4 {
+        Matrix4 {
+            rows: [
+                [1.0, 0.0, 0.0, v.x()],
+                [0.0, 1.0, 0.0, v.y()],
+                [0.0, 0.0, 1.0, v.z()],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Builds a homogeneous scaling matrix.
+    pub fn from_scaling(x: f64, y: f64, z: f64) -> Matrix4 {
+        Matrix4 {
+            rows: [
+                [x, 0.0, 0.0, 0.0],
+                [0.0, y, 0.0, 0.0],
+                [0.0, 0.0, z, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Builds a homogeneous rotation matrix about the x-axis.
+    pub fn from_rotation_x(radians: f64) -> Matrix4 {
+        let c = radians.cos();
+        let s = radians.sin();
+        Matrix4 {
+            rows: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, c, -s, 0.0],
+                [0.0, s, c, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Builds a homogeneous rotation matrix about the y-axis.
+    pub fn from_rotation_y(radians: f64) -> Matrix4 {
+        let c = radians.cos();
+        let s = radians.sin();
+        Matrix4 {
+            rows: [
+                [c, 0.0, s, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [-s, 0.0, c, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Builds a homogeneous rotation matrix about the z-axis.
+    pub fn from_rotation_z(radians: f64) -> Matrix4 {
+        let c = radians.cos();
+        let s = radians.sin();
+        Matrix4 {
+            rows: [
+                [c, -s, 0.0, 0.0],
+                [s, c, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Builds a homogeneous shearing matrix, where e.g. `x_by_y` is the
+    /// amount `x` shifts in proportion to `y`.
+    pub fn from_shearing(
+        x_by_y: f64,
+        x_by_z: f64,
+        y_by_x: f64,
+        y_by_z: f64,
+        z_by_x: f64,
+        z_by_y: f64,
+    ) -> Matrix4 {
+        Matrix4 {
+            rows: [
+                [1.0, x_by_y, x_by_z, 0.0],
+                [y_by_x, 1.0, y_by_z, 0.0],
+                [z_by_x, z_by_y, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Builds a right-handed view matrix for a camera at `eye` looking
+    /// toward `center`, with `up` disambiguating roll, following the
+    /// canonical `gluLookAt`/cgmath construction.
+    pub fn look_at(eye: CartesianVector, center: CartesianVector, up: CartesianVector) -> Matrix4 {
+        let forward = (center - eye).normalize();
+        let side = forward.cross(up).normalize();
+        let true_up = side.cross(forward);
+        Matrix4 {
+            rows: [
+                [side.x(), side.y(), side.z(), -side.dot(eye)],
+                [true_up.x(), true_up.y(), true_up.z(), -true_up.dot(eye)],
+                [-forward.x(), -forward.y(), -forward.z(), forward.dot(eye)],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Returns the element at `(row, col)`.
+    pub fn element(&self, row: usize, col: usize) -> f64 {
+        self.rows[row][col]
+    }
+
+    /// Multiplies this matrix by a homogeneous `[x, y, z, w]` vector.
+    pub fn multiply_vector(&self, v: [f64; 4]) -> [f64; 4] {
+        let mut result = [0.0; 4];
+        for (r, row) in self.rows.iter().enumerate() {
+            result[r] = row[0] * v[0] + row[1] * v[1] + row[2] * v[2] + row[3] * v[3];
+        }
+        result
+    }
+
+    /// Multiplies this matrix by another Matrix4.
+    pub fn multiply_matrix(&self, other: &Matrix4) -> Matrix4 {
+        let mut rows = [[0.0; 4]; 4];
+        for (r, row) in rows.iter_mut().enumerate() {
+            for (c, entry) in row.iter_mut().enumerate() {
+                *entry = (0..4).map(|k| self.rows[r][k] * other.rows[k][c]).sum();
+            }
+        }
+        Matrix4 { rows }
+    }
+
+    /// Applies this transform to `point`, dividing through by the
+    /// homogeneous `w` so perspective transforms behave correctly.
+    pub fn multiply_point(&self, point: CartesianVector) -> CartesianVector {
+        let result = self.multiply_vector([point.x(), point.y(), point.z(), 1.0]);
+        CartesianVector::new(result[0] / result[3], result[1] / result[3], result[2] / result[3])
+    }
+
+    /// Applies this transform to `direction`, ignoring the translation
+    /// column by treating the input as a direction with `w = 0`.
+    pub fn transform_vector(&self, direction: CartesianVector) -> CartesianVector {
+        let result = self.multiply_vector([direction.x(), direction.y(), direction.z(), 0.0]);
+        CartesianVector::new(result[0], result[1], result[2])
+    }
+}
+
+impl AsBytes for Matrix4 {
+    fn write_bytes(&self, buffer: &mut [u8]) {
+        copy_repr_c_bytes(self, buffer);
+    }
+
+    fn byte_len(&self) -> usize {
+        std::mem::size_of::<Matrix4>()
+    }
+}
+
+impl Mul<Matrix4> for Matrix4 {
+    type Output = Matrix4;
+
+    /// Composes two transforms, matching `multiply_matrix`.
+    fn mul(self, other: Matrix4) -> Matrix4 {
+        self.multiply_matrix(&other)
+    }
+}
+
+impl Mul<CartesianVector> for Matrix4 {
+    type Output = CartesianVector;
+
+    /// Applies this transform to a point, matching `multiply_point`.
+    fn mul(self, point: CartesianVector) -> CartesianVector {
+        self.multiply_point(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity() {
+        let identity = Matrix4::identity();
+        let v = [1.0, 2.0, 3.0, 1.0];
+        assert_eq!(identity.multiply_vector(v), v);
+    }
+
+    #[test]
+    fn test_from_translation() {
+        let m = Matrix4::from_translation(CartesianVector::new(1.0, 2.0, 3.0));
+        let point = [0.0, 0.0, 0.0, 1.0];
+        assert_eq!(m.multiply_vector(point), [1.0, 2.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_multiply_matrix() {
+        let translate_a = Matrix4::from_translation(CartesianVector::new(1.0, 0.0, 0.0));
+        let translate_b = Matrix4::from_translation(CartesianVector::new(0.0, 2.0, 0.0));
+        let combined = translate_a.multiply_matrix(&translate_b);
+        let point = [0.0, 0.0, 0.0, 1.0];
+        assert_eq!(combined.multiply_vector(point), [1.0, 2.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_from_scaling() {
+        let m = Matrix4::from_scaling(2.0, 3.0, 4.0);
+        let point = CartesianVector::new(1.0, 1.0, 1.0);
+        assert_eq!(m.multiply_point(point), CartesianVector::new(2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn test_from_rotation_z() {
+        let m = Matrix4::from_rotation_z(std::f64::consts::PI / 2.0);
+        assert!((m.element(0, 0) - 0.0).abs() < 1e-12);
+        assert!((m.element(0, 1) - -1.0).abs() < 1e-12);
+        assert!((m.element(1, 0) - 1.0).abs() < 1e-12);
+        assert!((m.element(1, 1) - 0.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_from_shearing() {
+        let m = Matrix4::from_shearing(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(m.element(0, 1), 1.0);
+        assert_eq!(m.element(0, 2), 2.0);
+        assert_eq!(m.element(1, 0), 3.0);
+        assert_eq!(m.element(1, 2), 4.0);
+        assert_eq!(m.element(2, 0), 5.0);
+        assert_eq!(m.element(2, 1), 6.0);
+    }
+
+    #[test]
+    fn test_multiply_point() {
+        let m = Matrix4::from_translation(CartesianVector::new(1.0, 2.0, 3.0));
+        let point = CartesianVector::new(0.0, 0.0, 0.0);
+        assert_eq!(m.multiply_point(point), CartesianVector::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_look_at_moves_eye_to_origin() {
+        let eye = CartesianVector::new(0.0, 0.0, 5.0);
+        let center = CartesianVector::new(0.0, 0.0, 0.0);
+        let up = CartesianVector::y_axis();
+        let view = Matrix4::look_at(eye, center, up);
+        let transformed_eye = view.multiply_point(eye);
+        assert!(transformed_eye.x().abs() < 1e-9);
+        assert!(transformed_eye.y().abs() < 1e-9);
+        assert!(transformed_eye.z().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mul_operator_composes_matrices() {
+        let translate = Matrix4::from_translation(CartesianVector::new(1.0, 0.0, 0.0));
+        let scale = Matrix4::from_scaling(2.0, 2.0, 2.0);
+        let combined = scale * translate;
+        let point = CartesianVector::new(0.0, 0.0, 0.0);
+        assert_eq!(combined.multiply_point(point), CartesianVector::new(2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_mul_operator_applies_to_point() {
+        let m = Matrix4::from_translation(CartesianVector::new(1.0, 2.0, 3.0));
+        let point = CartesianVector::new(0.0, 0.0, 0.0);
+        assert_eq!(m * point, CartesianVector::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_bytes_write_bytes() {
+        let m = Matrix4::identity();
+        assert_eq!(m.byte_len(), 128);
+        let mut buffer = [0u8; 128];
+        m.write_bytes(&mut buffer);
+        assert_eq!(&buffer[0..8], &1.0f64.to_le_bytes());
+        assert_eq!(&buffer[8..16], &0.0f64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_transform_vector_ignores_translation() {
+        let m = Matrix4::from_translation(CartesianVector::new(1.0, 2.0, 3.0));
+        let direction = CartesianVector::new(1.0, 0.0, 0.0);
+        assert_eq!(m.transform_vector(direction), direction);
+    }
+}
@@ -1,9 +1,21 @@
 //! Top-level module for general matrix operations
 
 pub mod functions;
+/// Module for the column-major, flat-storage `Matrix` type.
+pub mod matrix;
+/// Module for the fixed-size `Matrix3x3` type and its `std::ops` implementations.
+pub mod matrix3x3;
+/// Module for the column-based `Matrix3` rotation/transform type.
+pub mod matrix3;
+/// Module for the fixed-size `Matrix4` homogeneous transform type.
+pub mod matrix4;
+pub mod transforms;
 use super::vectors::CartesianVector;
+use crate::quaternions::Quaternion;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
 
 /// A struct representing a 3x3 matrix in Cartesian space
+#[derive(Copy, Clone)]
 pub struct CartesianMatrix {
     row_1: CartesianVector,
     row_2: CartesianVector,
@@ -37,6 +49,31 @@ impl CartesianMatrix {
         }
     }
 
+    /// Builds a CartesianMatrix from a column-major slice of nine entries.
+    pub fn from_column_slice(data: &[f64]) -> CartesianMatrix {
+        CartesianMatrix {
+            row_1: CartesianVector::new(data[0], data[3], data[6]),
+            row_2: CartesianVector::new(data[1], data[4], data[7]),
+            row_3: CartesianVector::new(data[2], data[5], data[8]),
+        }
+    }
+
+    /// Builds a CartesianMatrix from a row-major slice of nine entries.
+    pub fn from_row_slice(data: &[f64]) -> CartesianMatrix {
+        CartesianMatrix {
+            row_1: CartesianVector::new(data[0], data[1], data[2]),
+            row_2: CartesianVector::new(data[3], data[4], data[5]),
+            row_3: CartesianVector::new(data[6], data[7], data[8]),
+        }
+    }
+
+    /// Builds the rotation matrix for `radians` about `axis` by composing
+    /// it through a `Quaternion`, which avoids the gimbal-lock issues of
+    /// chaining per-axis rotations.
+    pub fn rotation_from_axis_angle(axis: CartesianVector, radians: f64) -> CartesianMatrix {
+        Quaternion::from_axis_angle(axis, radians).to_matrix()
+    }
+
     /// Adds two CartesianMatrices
     /// # Example
     /// ## Code
@@ -56,12 +93,9 @@ impl CartesianMatrix {
     ///     let result = matrix_a.plus(matrix_b);
     /// }
     /// ```
+    /// Thin wrapper over `Add` kept for backwards compatibility; prefer `self + other`.
     pub fn plus(&self, other: CartesianMatrix) -> CartesianMatrix {
-        CartesianMatrix {
-            row_1: self.row_1.plus(other.row_1),
-            row_2: self.row_2.plus(other.row_2),
-            row_3: self.row_3.plus(other.row_3),
-        }
+        *self + other
     }
 
     /// Subtracts two CartesianMatrices
@@ -83,12 +117,9 @@ impl CartesianMatrix {
     ///     let result = matrix_a.minus(matrix_b);
     /// }
     /// ```
+    /// Thin wrapper over `Sub` kept for backwards compatibility; prefer `self - other`.
     pub fn minus(&self, other: CartesianMatrix) -> CartesianMatrix {
-        CartesianMatrix {
-            row_1: self.row_1.minus(other.row_1),
-            row_2: self.row_2.minus(other.row_2),
-            row_3: self.row_3.minus(other.row_3),
-        }
+        *self - other
     }
 
     /// Scales a CartesianMatrix by an input multiple
@@ -106,12 +137,9 @@ impl CartesianMatrix {
     ///     let result = matrix.scale(2.0);
     /// }
     /// ```
+    /// Thin wrapper over `Mul<f64>` kept for backwards compatibility; prefer `self * scalar`.
     pub fn scale(&self, scalar: f64) -> CartesianMatrix {
-        CartesianMatrix {
-            row_1: self.row_1.scale(scalar),
-            row_2: self.row_2.scale(scalar),
-            row_3: self.row_3.scale(scalar),
-        }
+        *self * scalar
     }
 
     /// Returns a column of a CartesianMatrix
@@ -138,6 +166,27 @@ impl CartesianMatrix {
         }
     }
 
+    /// Returns the matrix's nine entries in column-major order:
+    /// `[row1.x, row2.x, row3.x, row1.y, row2.y, row3.y, row1.z, row2.z, row3.z]`.
+    pub fn as_column_major_array(&self) -> [f64; 9] {
+        [
+            self.row_1.x(),
+            self.row_2.x(),
+            self.row_3.x(),
+            self.row_1.y(),
+            self.row_2.y(),
+            self.row_3.y(),
+            self.row_1.z(),
+            self.row_2.z(),
+            self.row_3.z(),
+        ]
+    }
+
+    /// Returns an iterator over the nine entries in column-major order.
+    pub fn iter(&self) -> std::array::IntoIter<f64, 9> {
+        self.as_column_major_array().into_iter()
+    }
+
     /// Returns the first row of the calling CartesianMatrix
     /// # Example
     /// ## Code
@@ -273,6 +322,51 @@ impl CartesianMatrix {
             + self.row_1.z() * (self.row_2.x() * self.row_3.y() - self.row_2.y() * self.row_3.x())
     }
 
+    /// Converts this rotation matrix to a `Quaternion` using the standard
+    /// trace-based algorithm: when `trace = m00+m11+m22 > 0`, `w` is
+    /// derived directly from the trace; otherwise the largest diagonal
+    /// element is used as the pivot to avoid dividing by a near-zero `s`.
+    /// # Example
+    /// ## Code
+    /// ```rust
+    /// use linear_algebra::matrices::CartesianMatrix;
+    /// use linear_algebra::vectors::CartesianVector;
+    ///
+    /// fn main() {
+    ///     let matrix = CartesianMatrix::rotation_from_axis_angle(
+    ///         CartesianVector::z_axis(),
+    ///         std::f64::consts::PI / 2.0,
+    ///     );
+    ///     let result = matrix.to_quaternion();
+    /// }
+    /// ```
+    pub fn to_quaternion(&self) -> Quaternion {
+        let m00 = self.row_1.x();
+        let m01 = self.row_1.y();
+        let m02 = self.row_1.z();
+        let m10 = self.row_2.x();
+        let m11 = self.row_2.y();
+        let m12 = self.row_2.z();
+        let m20 = self.row_3.x();
+        let m21 = self.row_3.y();
+        let m22 = self.row_3.z();
+        let trace = m00 + m11 + m22;
+
+        if trace > 0.0 {
+            let s = 0.5 / (trace + 1.0).sqrt();
+            Quaternion::new(0.25 / s, (m21 - m12) * s, (m02 - m20) * s, (m10 - m01) * s)
+        } else if m00 > m11 && m00 > m22 {
+            let s = 2.0 * (1.0 + m00 - m11 - m22).sqrt();
+            Quaternion::new((m21 - m12) / s, 0.25 * s, (m01 + m10) / s, (m02 + m20) / s)
+        } else if m11 > m22 {
+            let s = 2.0 * (1.0 + m11 - m00 - m22).sqrt();
+            Quaternion::new((m02 - m20) / s, (m01 + m10) / s, 0.25 * s, (m12 + m21) / s)
+        } else {
+            let s = 2.0 * (1.0 + m22 - m00 - m11).sqrt();
+            Quaternion::new((m10 - m01) / s, (m02 + m20) / s, (m12 + m21) / s, 0.25 * s)
+        }
+    }
+
     /// Returns the transpose of the calling CartesianMatrix
     /// # Example
     /// ## Code
@@ -315,24 +409,9 @@ impl CartesianMatrix {
     ///     let result = matrix_a.multiply_matrix(matrix_b);
     /// }
     /// ```
+    /// Thin wrapper over `Mul<CartesianMatrix>` kept for backwards compatibility; prefer `self * other`.
     pub fn multiply_matrix(&self, other: CartesianMatrix) -> CartesianMatrix {
-        CartesianMatrix {
-            row_1: CartesianVector::new(
-                self.row_1.dot(other.column_1()),
-                self.row_1.dot(other.column_2()),
-                self.row_1.dot(other.column_3()),
-            ),
-            row_2: CartesianVector::new(
-                self.row_2.dot(other.column_1()),
-                self.row_2.dot(other.column_2()),
-                self.row_2.dot(other.column_3()),
-            ),
-            row_3: CartesianVector::new(
-                self.row_3.dot(other.column_1()),
-                self.row_3.dot(other.column_2()),
-                self.row_3.dot(other.column_3()),
-            ),
-        }
+        *self * other
     }
 
     /// Multiplies a CartesianMatrix by a CartesianVector
@@ -351,7 +430,280 @@ impl CartesianMatrix {
     ///     let result = matrix.multiply_vector(vector);
     /// }
     /// ```
+    /// Thin wrapper over `Mul<CartesianVector>` kept for backwards compatibility; prefer `self * other`.
     pub fn multiply_vector(&self, other: CartesianVector) -> CartesianVector {
+        *self * other
+    }
+
+    /// Returns the inverse of the calling CartesianMatrix, or `None` if its
+    /// determinant is near zero. Computed via the cofactor/adjugate method:
+    /// each cofactor `C_ij = (-1)^(i+j) * M_ij` is the signed 2x2 minor
+    /// determinant obtained by deleting row `i` and column `j`, the
+    /// cofactor matrix is transposed to form the adjugate, and every entry
+    /// is scaled by `1/det`.
+    /// # Example
+    /// ## Code
+    /// ```rust
+    /// use linear_algebra::matrices::CartesianMatrix;
+    /// use linear_algebra::vectors::CartesianVector;
+    ///
+    /// fn main() {
+    ///     let row_1 = CartesianVector::new(1.0, 2.0, 3.0);
+    ///     let row_2 = CartesianVector::new(0.0, 1.0, 4.0);
+    ///     let row_3 = CartesianVector::new(5.0, 6.0, 0.0);
+    ///     let matrix = CartesianMatrix::new(row_1, row_2, row_3);
+    ///     let result = matrix.inverse();
+    /// }
+    /// ```
+    pub fn inverse(&self) -> Option<CartesianMatrix> {
+        const EPSILON: f64 = 1e-9;
+
+        let det = self.determinant();
+        if det.abs() < EPSILON {
+            return None;
+        }
+
+        let a = [
+            [self.row_1.x(), self.row_1.y(), self.row_1.z()],
+            [self.row_2.x(), self.row_2.y(), self.row_2.z()],
+            [self.row_3.x(), self.row_3.y(), self.row_3.z()],
+        ];
+        let minor = |i: usize, j: usize| -> f64 {
+            let rows: Vec<usize> = (0..3).filter(|&r| r != i).collect();
+            let cols: Vec<usize> = (0..3).filter(|&c| c != j).collect();
+            a[rows[0]][cols[0]] * a[rows[1]][cols[1]] - a[rows[0]][cols[1]] * a[rows[1]][cols[0]]
+        };
+        let cofactor = |i: usize, j: usize| -> f64 {
+            let sign = if (i + j).is_multiple_of(2) { 1.0 } else { -1.0 };
+            sign * minor(i, j)
+        };
+
+        let mut cofactors = [[0.0; 3]; 3];
+        for (i, row) in cofactors.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = cofactor(i, j);
+            }
+        }
+
+        let inv_det = 1.0 / det;
+        Some(CartesianMatrix {
+            row_1: CartesianVector::new(
+                cofactors[0][0] * inv_det,
+                cofactors[1][0] * inv_det,
+                cofactors[2][0] * inv_det,
+            ),
+            row_2: CartesianVector::new(
+                cofactors[0][1] * inv_det,
+                cofactors[1][1] * inv_det,
+                cofactors[2][1] * inv_det,
+            ),
+            row_3: CartesianVector::new(
+                cofactors[0][2] * inv_det,
+                cofactors[1][2] * inv_det,
+                cofactors[2][2] * inv_det,
+            ),
+        })
+    }
+
+    /// Solves `self * x = b` for `x`, or returns `None` if the calling
+    /// matrix is singular.
+    /// # Example
+    /// ## Code
+    /// ```rust
+    /// use linear_algebra::matrices::CartesianMatrix;
+    /// use linear_algebra::vectors::CartesianVector;
+    ///
+    /// fn main() {
+    ///     let row_1 = CartesianVector::new(1.0, 2.0, 3.0);
+    ///     let row_2 = CartesianVector::new(0.0, 1.0, 4.0);
+    ///     let row_3 = CartesianVector::new(5.0, 6.0, 0.0);
+    ///     let matrix = CartesianMatrix::new(row_1, row_2, row_3);
+    ///     let b = CartesianVector::new(1.0, 2.0, 3.0);
+    ///     let result = matrix.solve(b);
+    /// }
+    /// ```
+    pub fn solve(&self, b: CartesianVector) -> Option<CartesianVector> {
+        Some(self.inverse()?.multiply_vector(b))
+    }
+
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix via
+    /// the cyclic Jacobi algorithm: repeatedly zero the largest off-diagonal
+    /// entry with a Givens rotation, accumulating the rotations into an
+    /// orthonormal eigenvector matrix, until the off-diagonal energy falls
+    /// below a tolerance or a sweep limit is reached. Returns the eigenvalues
+    /// as a `CartesianVector` and a `CartesianMatrix` whose columns are the
+    /// corresponding eigenvectors. Only meaningful for symmetric matrices.
+    /// # Example
+    /// ## Code
+    /// ```rust
+    /// use linear_algebra::matrices::CartesianMatrix;
+    /// use linear_algebra::vectors::CartesianVector;
+    ///
+    /// fn main() {
+    ///     let row_1 = CartesianVector::new(2.0, 1.0, 0.0);
+    ///     let row_2 = CartesianVector::new(1.0, 2.0, 0.0);
+    ///     let row_3 = CartesianVector::new(0.0, 0.0, 3.0);
+    ///     let matrix = CartesianMatrix::new(row_1, row_2, row_3);
+    ///     let (eigenvalues, eigenvectors) = matrix.symmetric_eigen();
+    /// }
+    /// ```
+    pub fn symmetric_eigen(&self) -> (CartesianVector, CartesianMatrix) {
+        const EPSILON: f64 = 1e-12;
+        const MAX_SWEEPS: usize = 100;
+
+        let mut a = [
+            [self.row_1.x(), self.row_1.y(), self.row_1.z()],
+            [self.row_2.x(), self.row_2.y(), self.row_2.z()],
+            [self.row_3.x(), self.row_3.y(), self.row_3.z()],
+        ];
+        let mut v = [
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ];
+
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal_sum_squares = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            if off_diagonal_sum_squares < EPSILON {
+                break;
+            }
+
+            let (p, q) = if a[0][1].abs() >= a[0][2].abs() && a[0][1].abs() >= a[1][2].abs() {
+                (0, 1)
+            } else if a[0][2].abs() >= a[1][2].abs() {
+                (0, 2)
+            } else {
+                (1, 2)
+            };
+
+            if a[p][q].abs() < EPSILON {
+                break;
+            }
+
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+
+            let a_pp = a[p][p];
+            let a_qq = a[q][q];
+            let a_pq = a[p][q];
+            a[p][p] = c * c * a_pp - 2.0 * s * c * a_pq + s * s * a_qq;
+            a[q][q] = s * s * a_pp + 2.0 * s * c * a_pq + c * c * a_qq;
+            a[p][q] = 0.0;
+            a[q][p] = 0.0;
+
+            let r = 3 - p - q;
+            let a_rp = a[r][p];
+            let a_rq = a[r][q];
+            a[r][p] = c * a_rp - s * a_rq;
+            a[p][r] = a[r][p];
+            a[r][q] = s * a_rp + c * a_rq;
+            a[q][r] = a[r][q];
+
+            for row in v.iter_mut() {
+                let v_p = row[p];
+                let v_q = row[q];
+                row[p] = c * v_p - s * v_q;
+                row[q] = s * v_p + c * v_q;
+            }
+        }
+
+        (
+            CartesianVector::new(a[0][0], a[1][1], a[2][2]),
+            CartesianMatrix::new(
+                CartesianVector::new(v[0][0], v[0][1], v[0][2]),
+                CartesianVector::new(v[1][0], v[1][1], v[1][2]),
+                CartesianVector::new(v[2][0], v[2][1], v[2][2]),
+            ),
+        )
+    }
+}
+
+impl Add for CartesianMatrix {
+    type Output = CartesianMatrix;
+
+    fn add(self, other: CartesianMatrix) -> CartesianMatrix {
+        CartesianMatrix {
+            row_1: self.row_1 + other.row_1,
+            row_2: self.row_2 + other.row_2,
+            row_3: self.row_3 + other.row_3,
+        }
+    }
+}
+
+impl Sub for CartesianMatrix {
+    type Output = CartesianMatrix;
+
+    fn sub(self, other: CartesianMatrix) -> CartesianMatrix {
+        CartesianMatrix {
+            row_1: self.row_1 - other.row_1,
+            row_2: self.row_2 - other.row_2,
+            row_3: self.row_3 - other.row_3,
+        }
+    }
+}
+
+impl Neg for CartesianMatrix {
+    type Output = CartesianMatrix;
+
+    fn neg(self) -> CartesianMatrix {
+        CartesianMatrix {
+            row_1: -self.row_1,
+            row_2: -self.row_2,
+            row_3: -self.row_3,
+        }
+    }
+}
+
+impl Mul<f64> for CartesianMatrix {
+    type Output = CartesianMatrix;
+
+    fn mul(self, scalar: f64) -> CartesianMatrix {
+        CartesianMatrix {
+            row_1: self.row_1 * scalar,
+            row_2: self.row_2 * scalar,
+            row_3: self.row_3 * scalar,
+        }
+    }
+}
+
+impl Mul<CartesianMatrix> for f64 {
+    type Output = CartesianMatrix;
+
+    fn mul(self, matrix: CartesianMatrix) -> CartesianMatrix {
+        matrix * self
+    }
+}
+
+impl Mul<CartesianMatrix> for CartesianMatrix {
+    type Output = CartesianMatrix;
+
+    fn mul(self, other: CartesianMatrix) -> CartesianMatrix {
+        CartesianMatrix {
+            row_1: CartesianVector::new(
+                self.row_1.dot(other.column_1()),
+                self.row_1.dot(other.column_2()),
+                self.row_1.dot(other.column_3()),
+            ),
+            row_2: CartesianVector::new(
+                self.row_2.dot(other.column_1()),
+                self.row_2.dot(other.column_2()),
+                self.row_2.dot(other.column_3()),
+            ),
+            row_3: CartesianVector::new(
+                self.row_3.dot(other.column_1()),
+                self.row_3.dot(other.column_2()),
+                self.row_3.dot(other.column_3()),
+            ),
+        }
+    }
+}
+
+impl Mul<CartesianVector> for CartesianMatrix {
+    type Output = CartesianVector;
+
+    fn mul(self, other: CartesianVector) -> CartesianVector {
         CartesianVector::new(
             self.row_1.dot(other),
             self.row_2.dot(other),
@@ -360,9 +712,39 @@ impl CartesianMatrix {
     }
 }
 
+impl Index<usize> for CartesianMatrix {
+    type Output = f64;
+
+    /// Indexes into the matrix's linear, column-major storage, so `m[0]`
+    /// is entry (0,0), `m[1]` is (1,0), `m[2]` is (2,0), `m[3]` is (0,1),
+    /// and so on.
+    fn index(&self, index: usize) -> &f64 {
+        let (row, col) = (index % 3, index / 3);
+        match row {
+            0 => self.row_1.component_ref(col),
+            1 => self.row_2.component_ref(col),
+            2 => self.row_3.component_ref(col),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl IndexMut<usize> for CartesianMatrix {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        let (row, col) = (index % 3, index / 3);
+        match row {
+            0 => self.row_1.component_mut(col),
+            1 => self.row_2.component_mut(col),
+            2 => self.row_3.component_mut(col),
+            _ => unreachable!(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use assert_approx_eq::assert_approx_eq;
 
     #[test]
     fn test_cartesian_matrix_plus() {
@@ -463,6 +845,55 @@ mod tests {
         assert_eq!(b, 0.0);
     }
 
+    #[test]
+    fn test_cartesian_matrix_to_quaternion_round_trip() {
+        let original = CartesianMatrix::rotation_from_axis_angle(
+            CartesianVector::new(1.0, 1.0, 0.0),
+            std::f64::consts::PI / 3.0,
+        );
+        let q = original.to_quaternion();
+        let rebuilt = q.to_matrix();
+        let v = CartesianVector::new(1.0, 2.0, 3.0);
+        let expected = original.multiply_vector(v);
+        let actual = rebuilt.multiply_vector(v);
+        assert_approx_eq!(actual.x(), expected.x(), 1e-9);
+        assert_approx_eq!(actual.y(), expected.y(), 1e-9);
+        assert_approx_eq!(actual.z(), expected.z(), 1e-9);
+    }
+
+    #[test]
+    fn test_cartesian_matrix_to_quaternion_identity() {
+        let identity = CartesianMatrix::new(
+            CartesianVector::new(1.0, 0.0, 0.0),
+            CartesianVector::new(0.0, 1.0, 0.0),
+            CartesianVector::new(0.0, 0.0, 1.0),
+        );
+        let q = identity.to_quaternion();
+        assert_approx_eq!(q.magnitude(), 1.0, 1e-12);
+        let rotated = q.rotate(CartesianVector::x_axis());
+        assert_approx_eq!(rotated.x(), 1.0, 1e-12);
+        assert_approx_eq!(rotated.y(), 0.0, 1e-12);
+        assert_approx_eq!(rotated.z(), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_cartesian_matrix_symmetric_eigen() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(2.0, 1.0, 0.0),
+            CartesianVector::new(1.0, 2.0, 0.0),
+            CartesianVector::new(0.0, 0.0, 3.0),
+        );
+        let (eigenvalues, eigenvectors) = a.symmetric_eigen();
+        let lambdas = [eigenvalues.x(), eigenvalues.y(), eigenvalues.z()];
+        for (i, lambda) in lambdas.iter().enumerate() {
+            let v = eigenvectors.column(i);
+            let av = a.multiply_vector(v);
+            assert_approx_eq!(av.x(), lambda * v.x(), 1e-9);
+            assert_approx_eq!(av.y(), lambda * v.y(), 1e-9);
+            assert_approx_eq!(av.z(), lambda * v.z(), 1e-9);
+        }
+    }
+
     #[test]
     fn test_cartesian_matrix_transpose() {
         let a = CartesianMatrix::new(
@@ -597,4 +1028,207 @@ mod tests {
         assert_eq!(b.y(), 6.0);
         assert_eq!(b.z(), 9.0);
     }
+
+    #[test]
+    fn test_cartesian_matrix_inverse() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(0.0, 1.0, 4.0),
+            CartesianVector::new(5.0, 6.0, 0.0),
+        );
+        let inverse = a.inverse().unwrap();
+        let identity = a.multiply_matrix(inverse);
+        assert_eq!(identity.row_1(), CartesianVector::new(1.0, 0.0, 0.0));
+        assert_eq!(identity.row_2(), CartesianVector::new(0.0, 1.0, 0.0));
+        assert_eq!(identity.row_3(), CartesianVector::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_inverse_singular() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        assert!(a.inverse().is_none());
+    }
+
+    #[test]
+    fn test_cartesian_matrix_solve() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(0.0, 1.0, 4.0),
+            CartesianVector::new(5.0, 6.0, 0.0),
+        );
+        let b = CartesianVector::new(14.0, 16.0, 17.0);
+        let x = a.solve(b).unwrap();
+        assert_eq!(a.multiply_vector(x), b);
+    }
+
+    #[test]
+    fn test_cartesian_matrix_solve_singular() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        assert!(a.solve(CartesianVector::new(1.0, 2.0, 3.0)).is_none());
+    }
+
+    #[test]
+    fn test_cartesian_matrix_add_operator() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        let b = CartesianMatrix::new(
+            CartesianVector::new(9.0, 8.0, 7.0),
+            CartesianVector::new(6.0, 5.0, 4.0),
+            CartesianVector::new(3.0, 2.0, 1.0),
+        );
+        let c = a + b;
+        assert_eq!(c.row_1(), CartesianVector::new(10.0, 10.0, 10.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_sub_operator() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        let b = CartesianMatrix::new(
+            CartesianVector::new(9.0, 8.0, 7.0),
+            CartesianVector::new(6.0, 5.0, 4.0),
+            CartesianVector::new(3.0, 2.0, 1.0),
+        );
+        let c = a - b;
+        assert_eq!(c.row_1(), CartesianVector::new(-8.0, -6.0, -4.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_neg_operator() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        let b = -a;
+        assert_eq!(b.row_1(), CartesianVector::new(-1.0, -2.0, -3.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_mul_scalar_operator() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        let b = a * 2.0;
+        assert_eq!(b.row_1(), CartesianVector::new(2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_scalar_mul_operator() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        let b = 2.0 * a;
+        assert_eq!(b.row_1(), CartesianVector::new(2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_mul_matrix_operator() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        let b = CartesianMatrix::new(
+            CartesianVector::new(9.0, 8.0, 7.0),
+            CartesianVector::new(6.0, 5.0, 4.0),
+            CartesianVector::new(3.0, 2.0, 1.0),
+        );
+        let c = a * b;
+        assert_eq!(c.row_1(), CartesianVector::new(30.0, 24.0, 18.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_mul_vector_operator() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        let b = CartesianVector::new(1.0, 2.0, 3.0);
+        let c = a * b;
+        assert_eq!(c, CartesianVector::new(14.0, 32.0, 50.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_as_column_major_array() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(
+            a.as_column_major_array(),
+            [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]
+        );
+    }
+
+    #[test]
+    fn test_cartesian_matrix_from_column_slice() {
+        let a = CartesianMatrix::from_column_slice(&[1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]);
+        assert_eq!(a.row_1(), CartesianVector::new(1.0, 2.0, 3.0));
+        assert_eq!(a.row_2(), CartesianVector::new(4.0, 5.0, 6.0));
+        assert_eq!(a.row_3(), CartesianVector::new(7.0, 8.0, 9.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_from_row_slice() {
+        let a = CartesianMatrix::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(a.row_1(), CartesianVector::new(1.0, 2.0, 3.0));
+        assert_eq!(a.row_2(), CartesianVector::new(4.0, 5.0, 6.0));
+        assert_eq!(a.row_3(), CartesianVector::new(7.0, 8.0, 9.0));
+    }
+
+    #[test]
+    fn test_cartesian_matrix_iter() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        let collected: Vec<f64> = a.iter().collect();
+        assert_eq!(collected, vec![1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]);
+    }
+
+    #[test]
+    fn test_cartesian_matrix_index() {
+        let a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(a[0], 1.0);
+        assert_eq!(a[1], 4.0);
+        assert_eq!(a[2], 7.0);
+        assert_eq!(a[3], 2.0);
+    }
+
+    #[test]
+    fn test_cartesian_matrix_index_mut() {
+        let mut a = CartesianMatrix::new(
+            CartesianVector::new(1.0, 2.0, 3.0),
+            CartesianVector::new(4.0, 5.0, 6.0),
+            CartesianVector::new(7.0, 8.0, 9.0),
+        );
+        a[0] = 100.0;
+        assert_eq!(a.row_1(), CartesianVector::new(100.0, 2.0, 3.0));
+    }
 }
@@ -0,0 +1,183 @@
+//! Module for building 4x4 homogeneous affine transformation matrices
+//!
+//! Each builder returns a `Vec<Vec<f64>>` compatible with
+//! [`super::functions::multiply`] and [`super::functions::identity`], so
+//! transforms can be composed by chaining `multiply` calls. The formulas
+//! themselves live on [`super::matrix4::Matrix4`]; these are thin
+//! `Vec<Vec<f64>>`-converting wrappers kept for code still built around the
+//! arbitrary-dimension `functions` module.
+
+use super::matrix4::Matrix4;
+use crate::vectors::CartesianVector;
+
+fn to_vec(m: Matrix4) -> Vec<Vec<f64>> {
+    (0..4)
+        .map(|row| (0..4).map(|col| m.element(row, col)).collect())
+        .collect()
+}
+
+/// Builds a 4x4 translation matrix
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::transforms::translation;
+///
+/// fn main() {
+///     let result = translation(1.0, 2.0, 3.0);
+///     println!("{:?}", result);
+/// }
+/// ```
+/// ## Terminal
+/// ```bash
+/// $ cargo run
+/// [[1.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 2.0], [0.0, 0.0, 1.0, 3.0], [0.0, 0.0, 0.0, 1.0]]
+/// ```
+pub fn translation(x: f64, y: f64, z: f64) -> Vec<Vec<f64>> {
+    to_vec(Matrix4::from_translation(CartesianVector::new(x, y, z)))
+}
+
+/// Builds a 4x4 scaling matrix
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::transforms::scaling;
+///
+/// fn main() {
+///     let result = scaling(2.0, 3.0, 4.0);
+///     println!("{:?}", result);
+/// }
+/// ```
+/// ## Terminal
+/// ```bash
+/// $ cargo run
+/// [[2.0, 0.0, 0.0, 0.0], [0.0, 3.0, 0.0, 0.0], [0.0, 0.0, 4.0, 0.0], [0.0, 0.0, 0.0, 1.0]]
+/// ```
+pub fn scaling(x: f64, y: f64, z: f64) -> Vec<Vec<f64>> {
+    to_vec(Matrix4::from_scaling(x, y, z))
+}
+
+/// Builds a 4x4 rotation matrix about the x-axis
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::transforms::rotation_x;
+///
+/// fn main() {
+///     let result = rotation_x(std::f64::consts::PI / 2.0);
+///     println!("{:?}", result);
+/// }
+/// ```
+pub fn rotation_x(r: f64) -> Vec<Vec<f64>> {
+    to_vec(Matrix4::from_rotation_x(r))
+}
+
+/// Builds a 4x4 rotation matrix about the y-axis
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::transforms::rotation_y;
+///
+/// fn main() {
+///     let result = rotation_y(std::f64::consts::PI / 2.0);
+///     println!("{:?}", result);
+/// }
+/// ```
+pub fn rotation_y(r: f64) -> Vec<Vec<f64>> {
+    to_vec(Matrix4::from_rotation_y(r))
+}
+
+/// Builds a 4x4 rotation matrix about the z-axis
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::transforms::rotation_z;
+///
+/// fn main() {
+///     let result = rotation_z(std::f64::consts::PI / 2.0);
+///     println!("{:?}", result);
+/// }
+/// ```
+pub fn rotation_z(r: f64) -> Vec<Vec<f64>> {
+    to_vec(Matrix4::from_rotation_z(r))
+}
+
+/// Builds a 4x4 shearing matrix
+/// # Example
+/// ## Code
+/// ```rust
+/// use linear_algebra::matrices::transforms::shearing;
+///
+/// fn main() {
+///     let result = shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+///     println!("{:?}", result);
+/// }
+/// ```
+pub fn shearing(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Vec<Vec<f64>> {
+    to_vec(Matrix4::from_shearing(xy, xz, yx, yz, zx, zy))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translation() {
+        let result = translation(1.0, 2.0, 3.0);
+        assert_eq!(
+            result,
+            vec![
+                vec![1.0, 0.0, 0.0, 1.0],
+                vec![0.0, 1.0, 0.0, 2.0],
+                vec![0.0, 0.0, 1.0, 3.0],
+                vec![0.0, 0.0, 0.0, 1.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scaling() {
+        let result = scaling(2.0, 3.0, 4.0);
+        assert_eq!(
+            result,
+            vec![
+                vec![2.0, 0.0, 0.0, 0.0],
+                vec![0.0, 3.0, 0.0, 0.0],
+                vec![0.0, 0.0, 4.0, 0.0],
+                vec![0.0, 0.0, 0.0, 1.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rotation_z() {
+        let result = rotation_z(std::f64::consts::PI / 2.0);
+        assert!((result[0][0] - 0.0).abs() < 1e-12);
+        assert!((result[0][1] - -1.0).abs() < 1e-12);
+        assert!((result[1][0] - 1.0).abs() < 1e-12);
+        assert!((result[1][1] - 0.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_shearing() {
+        let result = shearing(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(
+            result,
+            vec![
+                vec![1.0, 1.0, 2.0, 0.0],
+                vec![3.0, 1.0, 4.0, 0.0],
+                vec![5.0, 6.0, 1.0, 0.0],
+                vec![0.0, 0.0, 0.0, 1.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_translation_composes_with_multiply() {
+        use super::super::functions::multiply;
+        let t = translation(1.0, 0.0, 0.0);
+        let s = scaling(2.0, 2.0, 2.0);
+        let result = multiply(t, s);
+        assert_eq!(result[0][0], 2.0);
+        assert_eq!(result[0][3], 1.0);
+    }
+}
@@ -0,0 +1,302 @@
+//! Module for unit quaternions used to compose and interpolate rotations
+//!
+//! `CartesianVector::rotate_about_axis` only exposes a single axis-angle
+//! rotation; [`Quaternion`] makes rotations composable (`multiply`) and
+//! interpolatable (`slerp`).
+
+use crate::matrices::matrix3x3::Matrix3x3;
+use crate::matrices::CartesianMatrix;
+use crate::vectors::vector3d::Vector3D;
+use crate::vectors::CartesianVector;
+
+/// A quaternion with scalar part `w` and vector part `(x, y, z)`
+#[derive(Copy, Clone, Debug)]
+pub struct Quaternion {
+    w: f64,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Quaternion {
+    /// Creates a new Quaternion from its `w`, `x`, `y`, and `z` components.
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Quaternion {
+        Quaternion { w, x, y, z }
+    }
+
+    /// Builds the unit quaternion representing a rotation of `angle` radians
+    /// about `axis`.
+    /// # Example
+    /// ```rust
+    /// use linear_algebra::quaternions::Quaternion;
+    /// use linear_algebra::vectors::CartesianVector;
+    ///
+    /// let q = Quaternion::from_axis_angle(CartesianVector::z_axis(), std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn from_axis_angle(axis: CartesianVector, angle: f64) -> Quaternion {
+        let half_angle = angle / 2.0;
+        let axis = axis.normalize();
+        let s = half_angle.sin();
+        Quaternion {
+            w: half_angle.cos(),
+            x: axis.x() * s,
+            y: axis.y() * s,
+            z: axis.z() * s,
+        }
+    }
+
+    /// Composes this rotation with `other` via the Hamilton product, so that
+    /// `a.multiply(&b)` applies `b` first, then `a`.
+    pub fn multiply(&self, other: &Quaternion) -> Quaternion {
+        Quaternion {
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        }
+    }
+
+    /// Returns the conjugate of this quaternion, which represents the
+    /// inverse rotation for a unit quaternion.
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    /// Calculates the magnitude of this quaternion.
+    pub fn magnitude(&self) -> f64 {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Returns this quaternion scaled to unit magnitude.
+    pub fn normalize(&self) -> Quaternion {
+        let mag = self.magnitude();
+        Quaternion {
+            w: self.w / mag,
+            x: self.x / mag,
+            y: self.y / mag,
+            z: self.z / mag,
+        }
+    }
+
+    /// Rotates `v` by this quaternion.
+    pub fn rotate(&self, v: CartesianVector) -> CartesianVector {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        let m00 = 1.0 - 2.0 * (y * y + z * z);
+        let m01 = 2.0 * (x * y - w * z);
+        let m02 = 2.0 * (x * z + w * y);
+        let m10 = 2.0 * (x * y + w * z);
+        let m11 = 1.0 - 2.0 * (x * x + z * z);
+        let m12 = 2.0 * (y * z - w * x);
+        let m20 = 2.0 * (x * z - w * y);
+        let m21 = 2.0 * (y * z + w * x);
+        let m22 = 1.0 - 2.0 * (x * x + y * y);
+        CartesianVector::new(
+            m00 * v.x() + m01 * v.y() + m02 * v.z(),
+            m10 * v.x() + m11 * v.y() + m12 * v.z(),
+            m20 * v.x() + m21 * v.y() + m22 * v.z(),
+        )
+    }
+
+    /// Builds the rotation matrix this quaternion represents, normalizing
+    /// first so the result is a valid rotation even if `self` has drifted
+    /// from unit magnitude.
+    pub fn to_matrix(&self) -> CartesianMatrix {
+        let q = self.normalize();
+        let (w, x, y, z) = (q.w, q.x, q.y, q.z);
+        CartesianMatrix::new(
+            CartesianVector::new(
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ),
+            CartesianVector::new(
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ),
+            CartesianVector::new(
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ),
+        )
+    }
+
+    /// Rotates a `Vector3D` by this quaternion, for callers working in the
+    /// `Vector3D`/`Matrix3x3` family rather than `CartesianVector`.
+    pub fn rotate_vector3d(&self, v: Vector3D) -> Vector3D {
+        self.rotate(CartesianVector::from(v)).into()
+    }
+
+    /// Builds the `Matrix3x3` rotation this quaternion represents, the
+    /// `Vector3D`-family counterpart of [`Quaternion::to_matrix`].
+    pub fn to_matrix3x3(&self) -> Matrix3x3 {
+        let m = self.to_matrix();
+        Matrix3x3::new(
+            m.row_1().into(),
+            m.row_2().into(),
+            m.row_3().into(),
+        )
+    }
+
+    /// Recovers the `(axis, angle)` pair this quaternion represents.
+    pub fn to_axis_angle(&self) -> (CartesianVector, f64) {
+        let q = self.normalize();
+        let angle = 2.0 * q.w.acos();
+        let s = (1.0 - q.w * q.w).sqrt();
+        let axis = if s < 1e-9 {
+            CartesianVector::x_axis()
+        } else {
+            CartesianVector::new(q.x / s, q.y / s, q.z / s)
+        };
+        (axis, angle)
+    }
+
+    /// Spherically interpolates between this quaternion and `other` by
+    /// `t` in `[0, 1]`, falling back to a normalized linear interpolation
+    /// when the quaternions are nearly identical to avoid dividing by a
+    /// near-zero `sin(theta_0)`.
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+        let mut other = *other;
+        let mut d = self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z;
+        if d < 0.0 {
+            other = Quaternion::new(-other.w, -other.x, -other.y, -other.z);
+            d = -d;
+        }
+
+        if d > 0.9995 {
+            let result = Quaternion::new(
+                self.w + t * (other.w - self.w),
+                self.x + t * (other.x - self.x),
+                self.y + t * (other.y - self.y),
+                self.z + t * (other.z - self.z),
+            );
+            return result.normalize();
+        }
+
+        let theta_0 = d.acos();
+        let theta = theta_0 * t;
+        let sin_theta_0 = theta_0.sin();
+        let sin_theta = theta.sin();
+        let s0 = theta.cos() - d * sin_theta / sin_theta_0;
+        let s1 = sin_theta / sin_theta_0;
+        Quaternion::new(
+            self.w * s0 + other.w * s1,
+            self.x * s0 + other.x * s1,
+            self.y * s0 + other.y * s1,
+            self.z * s0 + other.z * s1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_from_axis_angle() {
+        let q = Quaternion::from_axis_angle(CartesianVector::z_axis(), std::f64::consts::PI / 2.0);
+        assert_approx_eq!(q.w, (std::f64::consts::PI / 4.0).cos(), 1e-12);
+        assert_approx_eq!(q.z, (std::f64::consts::PI / 4.0).sin(), 1e-12);
+    }
+
+    #[test]
+    fn test_multiply_identity() {
+        let q = Quaternion::from_axis_angle(CartesianVector::z_axis(), std::f64::consts::PI / 2.0);
+        let identity = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+        let result = q.multiply(&identity);
+        assert_approx_eq!(result.w, q.w, 1e-12);
+        assert_approx_eq!(result.x, q.x, 1e-12);
+        assert_approx_eq!(result.y, q.y, 1e-12);
+        assert_approx_eq!(result.z, q.z, 1e-12);
+    }
+
+    #[test]
+    fn test_conjugate() {
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        let c = q.conjugate();
+        assert_eq!(c.w, 1.0);
+        assert_eq!(c.x, -2.0);
+        assert_eq!(c.y, -3.0);
+        assert_eq!(c.z, -4.0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let q = Quaternion::new(2.0, 0.0, 0.0, 0.0);
+        let n = q.normalize();
+        assert_approx_eq!(n.magnitude(), 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let q = Quaternion::from_axis_angle(CartesianVector::z_axis(), std::f64::consts::PI / 2.0);
+        let rotated = q.rotate(CartesianVector::x_axis());
+        assert_approx_eq!(rotated.x(), 0.0, 1e-12);
+        assert_approx_eq!(rotated.y(), 1.0, 1e-12);
+        assert_approx_eq!(rotated.z(), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_to_matrix() {
+        let q = Quaternion::from_axis_angle(CartesianVector::z_axis(), std::f64::consts::PI / 2.0);
+        let matrix = q.to_matrix();
+        let rotated = matrix.multiply_vector(CartesianVector::x_axis());
+        assert_approx_eq!(rotated.x(), 0.0, 1e-12);
+        assert_approx_eq!(rotated.y(), 1.0, 1e-12);
+        assert_approx_eq!(rotated.z(), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_rotate_vector3d() {
+        let q = Quaternion::from_axis_angle(CartesianVector::z_axis(), std::f64::consts::PI / 2.0);
+        let rotated = q.rotate_vector3d(Vector3D::new(1.0, 0.0, 0.0));
+        assert_approx_eq!(rotated.element(0), 0.0, 1e-12);
+        assert_approx_eq!(rotated.element(1), 1.0, 1e-12);
+        assert_approx_eq!(rotated.element(2), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_to_matrix3x3() {
+        let q = Quaternion::from_axis_angle(CartesianVector::z_axis(), std::f64::consts::PI / 2.0);
+        let matrix = q.to_matrix3x3();
+        let rotated = matrix * Vector3D::new(1.0, 0.0, 0.0);
+        assert_approx_eq!(rotated.element(0), 0.0, 1e-12);
+        assert_approx_eq!(rotated.element(1), 1.0, 1e-12);
+        assert_approx_eq!(rotated.element(2), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_to_axis_angle_round_trip() {
+        let angle = std::f64::consts::PI / 3.0;
+        let q = Quaternion::from_axis_angle(CartesianVector::z_axis(), angle);
+        let (axis, recovered_angle) = q.to_axis_angle();
+        assert_approx_eq!(recovered_angle, angle, 1e-12);
+        assert_approx_eq!(axis.z(), 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_slerp_endpoints() {
+        let a = Quaternion::from_axis_angle(CartesianVector::z_axis(), 0.0);
+        let b = Quaternion::from_axis_angle(CartesianVector::z_axis(), std::f64::consts::PI / 2.0);
+        let start = a.slerp(&b, 0.0);
+        let end = a.slerp(&b, 1.0);
+        assert_approx_eq!(start.w, a.w, 1e-9);
+        assert_approx_eq!(end.w, b.w, 1e-9);
+        assert_approx_eq!(end.z, b.z, 1e-9);
+    }
+
+    #[test]
+    fn test_slerp_midpoint_is_unit() {
+        let a = Quaternion::from_axis_angle(CartesianVector::z_axis(), 0.0);
+        let b = Quaternion::from_axis_angle(CartesianVector::z_axis(), std::f64::consts::PI / 2.0);
+        let mid = a.slerp(&b, 0.5);
+        assert_approx_eq!(mid.magnitude(), 1.0, 1e-9);
+    }
+}
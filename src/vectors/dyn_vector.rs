@@ -0,0 +1,192 @@
+//! Module for a dynamically-sized vector with bounds-checked arithmetic
+//!
+//! The [`functions`](super::functions) free functions loop over `a.len()`
+//! and index `b[i]`, so they panic or silently truncate on mismatched lengths.
+//! [`DynVector`] wraps the same arbitrary-length data but returns a
+//! [`LengthMismatch`] error instead, and extends the `magnitude`,
+//! `normalize`, `project_on`, and `angle_between` helpers `CartesianVector`
+//! already enjoys to vectors of any dimension.
+
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when two `DynVector`s of different lengths are combined.
+#[derive(Debug, PartialEq)]
+pub struct LengthMismatch {
+    left: usize,
+    right: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "vector length mismatch: {} vs {}",
+            self.left, self.right
+        )
+    }
+}
+
+impl Error for LengthMismatch {}
+
+/// A vector of arbitrary length, backed by a `Vec<f64>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynVector(Vec<f64>);
+
+impl DynVector {
+    /// Creates a new DynVector from a vector of values.
+    pub fn new(values: Vec<f64>) -> DynVector {
+        DynVector(values)
+    }
+
+    /// Returns the number of components in this vector.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if this vector has no components.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns this vector's components as a slice.
+    pub fn as_slice(&self) -> &[f64] {
+        &self.0
+    }
+
+    fn check_len(&self, other: &DynVector) -> Result<(), LengthMismatch> {
+        if self.len() == other.len() {
+            Ok(())
+        } else {
+            Err(LengthMismatch {
+                left: self.len(),
+                right: other.len(),
+            })
+        }
+    }
+
+    /// Adds two vectors of equal length, or returns a `LengthMismatch`.
+    pub fn add(&self, other: &DynVector) -> Result<DynVector, LengthMismatch> {
+        self.check_len(other)?;
+        Ok(DynVector(
+            self.0.iter().zip(&other.0).map(|(a, b)| a + b).collect(),
+        ))
+    }
+
+    /// Subtracts two vectors of equal length, or returns a `LengthMismatch`.
+    pub fn subtract(&self, other: &DynVector) -> Result<DynVector, LengthMismatch> {
+        self.check_len(other)?;
+        Ok(DynVector(
+            self.0.iter().zip(&other.0).map(|(a, b)| a - b).collect(),
+        ))
+    }
+
+    /// Calculates the dot product of two vectors of equal length, or
+    /// returns a `LengthMismatch`.
+    pub fn dot(&self, other: &DynVector) -> Result<f64, LengthMismatch> {
+        self.check_len(other)?;
+        Ok(self.0.iter().zip(&other.0).map(|(a, b)| a * b).sum())
+    }
+
+    /// Scales this vector by a scalar.
+    pub fn scale(&self, scalar: f64) -> DynVector {
+        DynVector(self.0.iter().map(|v| v * scalar).collect())
+    }
+
+    /// Calculates the magnitude of this vector.
+    pub fn magnitude(&self) -> f64 {
+        self.0.iter().map(|v| v * v).sum::<f64>().sqrt()
+    }
+
+    /// Returns this vector scaled to unit magnitude.
+    pub fn normalize(&self) -> DynVector {
+        self.scale(1.0 / self.magnitude())
+    }
+
+    /// Projects this vector onto `other`, or returns a `LengthMismatch`.
+    pub fn project_on(&self, other: &DynVector) -> Result<DynVector, LengthMismatch> {
+        let numerator = self.dot(other)?;
+        let denominator = other.dot(other)?;
+        Ok(other.scale(numerator / denominator))
+    }
+
+    /// Calculates the angle in radians between this vector and `other`,
+    /// clamping the cosine to `[-1, 1]` to avoid `NaN` from rounding error,
+    /// or returns a `LengthMismatch`.
+    pub fn angle_between(&self, other: &DynVector) -> Result<f64, LengthMismatch> {
+        let cosine = self.dot(other)? / (self.magnitude() * other.magnitude());
+        Ok(cosine.clamp(-1.0, 1.0).acos())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        let a = DynVector::new(vec![1.0, 2.0, 3.0]);
+        let b = DynVector::new(vec![4.0, 5.0, 6.0]);
+        assert_eq!(a.add(&b), Ok(DynVector::new(vec![5.0, 7.0, 9.0])));
+    }
+
+    #[test]
+    fn test_add_length_mismatch() {
+        let a = DynVector::new(vec![1.0, 2.0, 3.0]);
+        let b = DynVector::new(vec![4.0, 5.0]);
+        assert_eq!(a.add(&b), Err(LengthMismatch { left: 3, right: 2 }));
+    }
+
+    #[test]
+    fn test_subtract() {
+        let a = DynVector::new(vec![1.0, 2.0, 3.0]);
+        let b = DynVector::new(vec![4.0, 5.0, 6.0]);
+        assert_eq!(a.subtract(&b), Ok(DynVector::new(vec![-3.0, -3.0, -3.0])));
+    }
+
+    #[test]
+    fn test_dot() {
+        let a = DynVector::new(vec![1.0, 2.0, 3.0]);
+        let b = DynVector::new(vec![4.0, 5.0, 6.0]);
+        assert_eq!(a.dot(&b), Ok(32.0));
+    }
+
+    #[test]
+    fn test_dot_length_mismatch() {
+        let a = DynVector::new(vec![1.0, 2.0, 3.0]);
+        let b = DynVector::new(vec![4.0, 5.0]);
+        assert_eq!(a.dot(&b), Err(LengthMismatch { left: 3, right: 2 }));
+    }
+
+    #[test]
+    fn test_scale() {
+        let a = DynVector::new(vec![1.0, 2.0, 3.0]);
+        assert_eq!(a.scale(2.0), DynVector::new(vec![2.0, 4.0, 6.0]));
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let a = DynVector::new(vec![3.0, 4.0]);
+        assert_eq!(a.magnitude(), 5.0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let a = DynVector::new(vec![3.0, 4.0]);
+        assert_eq!(a.normalize().magnitude(), 1.0);
+    }
+
+    #[test]
+    fn test_project_on() {
+        let a = DynVector::new(vec![1.0, 1.0, 0.0]);
+        let b = DynVector::new(vec![1.0, 0.0, 0.0]);
+        assert_eq!(a.project_on(&b), Ok(DynVector::new(vec![1.0, 0.0, 0.0])));
+    }
+
+    #[test]
+    fn test_angle_between() {
+        let a = DynVector::new(vec![1.0, 0.0]);
+        let b = DynVector::new(vec![0.0, 1.0]);
+        assert_eq!(a.angle_between(&b), Ok(std::f64::consts::PI / 2.0));
+    }
+}
@@ -25,6 +25,25 @@
 //! * rotate_about_axis
 //!
 
+/// Module for a dynamically-sized vector with bounds-checked arithmetic.
+pub mod dyn_vector;
+/// Module used to perform basic operations on vectors of arbitrary length.
+pub mod functions;
+/// Module for unit-tagged `Vector3D<Unit>`/`Point3D<Unit>` types with
+/// compile-time coordinate-space safety.
+pub mod tagged;
+/// Module for the fixed-size `Vector2D` type and its `std::ops` implementations.
+pub mod vector2d;
+/// Module for the fixed-size `Vector3D` type and its `std::ops` implementations.
+pub mod vector3d;
+
+use crate::bytes::AsBytes;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Tolerance used by `CartesianVector`'s `PartialEq` implementation, since
+/// floating-point arithmetic rarely produces bit-exact results.
+const EPSILON: f64 = 1e-9;
+
 /// A 3D vector with x, y, and z components
 #[derive(Copy, Clone, Debug)]
 pub struct CartesianVector {
@@ -131,6 +150,28 @@ impl CartesianVector {
         self.z
     }
 
+    /// Returns a reference to the component at `index` (0 = x, 1 = y,
+    /// 2 = z), used by `CartesianMatrix`'s linear indexing.
+    pub(crate) fn component_ref(&self, index: usize) -> &f64 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Invalid component index"),
+        }
+    }
+
+    /// Returns a mutable reference to the component at `index` (0 = x,
+    /// 1 = y, 2 = z), used by `CartesianMatrix`'s linear indexing.
+    pub(crate) fn component_mut(&mut self, index: usize) -> &mut f64 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Invalid component index"),
+        }
+    }
+
     /// Add two CartesianVectors
     /// # Example
     /// ```rust
@@ -140,12 +181,9 @@ impl CartesianVector {
     /// let v3 = v1.plus(v2);
     /// println!("{:?}", v3); // [5.0, 7.0, 9.0]
     /// ```
+    /// Thin wrapper over `Add` kept for backwards compatibility; prefer `self + other`.
     pub fn plus(&self, other: CartesianVector) -> CartesianVector {
-        CartesianVector {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-        }
+        *self + other
     }
 
     /// Subtract two CartesianVectors
@@ -157,12 +195,9 @@ impl CartesianVector {
     /// let v3 = v1.minus(v2);
     /// println!("{:?}", v3); // [-3.0, -3.0, -3.0]
     /// ```
+    /// Thin wrapper over `Sub` kept for backwards compatibility; prefer `self - other`.
     pub fn minus(&self, other: CartesianVector) -> CartesianVector {
-        CartesianVector {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-        }
+        *self - other
     }
 
     /// Calculate the dot product of two CartesianVectors
@@ -174,8 +209,9 @@ impl CartesianVector {
     /// let v3 = v1.dot(v2);
     /// println!("{:?}", v3); // 32.0
     /// ```
+    /// Thin wrapper over `Mul<CartesianVector>` kept for backwards compatibility; prefer `self * other`.
     pub fn dot(&self, other: CartesianVector) -> f64 {
-        self.x * other.x + self.y * other.y + self.z * other.z
+        *self * other
     }
 
     /// Scale a CartesianVector by a scalar
@@ -186,12 +222,9 @@ impl CartesianVector {
     /// let v2 = v1.scale(2.0);
     /// println!("{:?}", v2); // [2.0, 4.0, 6.0]
     /// ```
+    /// Thin wrapper over `Mul<f64>` kept for backwards compatibility; prefer `self * scalar`.
     pub fn scale(&self, scalar: f64) -> CartesianVector {
-        CartesianVector {
-            x: self.x * scalar,
-            y: self.y * scalar,
-            z: self.z * scalar,
-        }
+        *self * scalar
     }
 
     /// Calculate the cross product of two CartesianVectors
@@ -336,6 +369,204 @@ impl CartesianVector {
             + self.z * (c + axis.z * axis.z * t);
         CartesianVector { x, y, z }
     }
+
+    /// Projects this vector onto `other`, returning the component of `self`
+    /// that lies along `other`'s direction.
+    /// # Example
+    /// ```rust
+    /// use linear_algebra::vectors::CartesianVector;
+    /// let v1 = CartesianVector::new(1.0, 1.0, 0.0);
+    /// let v2 = CartesianVector::x_axis();
+    /// let v3 = v1.project_on(v2);
+    /// println!("{:?}", v3); // CartesianVector { x: 1.0, y: 0.0, z: 0.0 }
+    /// ```
+    pub fn project_on(&self, other: CartesianVector) -> CartesianVector {
+        other.scale(self.dot(other) / other.dot(other))
+    }
+
+    /// Rejects this vector from `other`, returning the component of `self`
+    /// that is perpendicular to `other`'s direction.
+    /// # Example
+    /// ```rust
+    /// use linear_algebra::vectors::CartesianVector;
+    /// let v1 = CartesianVector::new(1.0, 1.0, 0.0);
+    /// let v2 = CartesianVector::x_axis();
+    /// let v3 = v1.reject_from(v2);
+    /// println!("{:?}", v3); // CartesianVector { x: 0.0, y: 1.0, z: 0.0 }
+    /// ```
+    pub fn reject_from(&self, other: CartesianVector) -> CartesianVector {
+        self.minus(self.project_on(other))
+    }
+
+    /// Calculates the angle in radians between this vector and `other`,
+    /// clamping the cosine to `[-1, 1]` to avoid `NaN` from rounding error.
+    /// # Example
+    /// ```rust
+    /// use linear_algebra::vectors::CartesianVector;
+    /// let v1 = CartesianVector::x_axis();
+    /// let v2 = CartesianVector::y_axis();
+    /// let angle = v1.angle_between(v2);
+    /// println!("{:?}", angle); // 1.5707963267948966
+    /// ```
+    pub fn angle_between(&self, other: CartesianVector) -> f64 {
+        let cosine = self.dot(other) / (self.magnitude() * other.magnitude());
+        cosine.clamp(-1.0, 1.0).acos()
+    }
+
+    /// Reflects this vector off a surface with the given unit `normal`.
+    /// # Example
+    /// ```rust
+    /// use linear_algebra::vectors::CartesianVector;
+    /// let v1 = CartesianVector::new(1.0, -1.0, 0.0);
+    /// let normal = CartesianVector::y_axis();
+    /// let v2 = v1.reflect(normal);
+    /// println!("{:?}", v2); // CartesianVector { x: 1.0, y: 1.0, z: 0.0 }
+    /// ```
+    pub fn reflect(&self, normal: CartesianVector) -> CartesianVector {
+        self.minus(normal.scale(2.0 * self.dot(normal)))
+    }
+
+    /// Returns this vector's components as a `[x, y, z]` array.
+    /// # Example
+    /// ```rust
+    /// use linear_algebra::vectors::CartesianVector;
+    /// let v1 = CartesianVector::new(1.0, 2.0, 3.0);
+    /// let a = v1.as_array();
+    /// println!("{:?}", a); // [1.0, 2.0, 3.0]
+    /// ```
+    pub fn as_array(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Reconstructs a CartesianVector from the little-endian bytes written
+    /// by [`AsBytes::write_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> CartesianVector {
+        let x = f64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let y = f64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        let z = f64::from_le_bytes(bytes[16..24].try_into().unwrap());
+        CartesianVector { x, y, z }
+    }
+}
+
+impl AsBytes for CartesianVector {
+    /// Writes this vector's `x`, `y`, and `z` components as contiguous
+    /// little-endian bytes, suitable for a `memcpy` into a GPU buffer.
+    fn write_bytes(&self, buffer: &mut [u8]) {
+        buffer[0..8].copy_from_slice(&self.x.to_le_bytes());
+        buffer[8..16].copy_from_slice(&self.y.to_le_bytes());
+        buffer[16..24].copy_from_slice(&self.z.to_le_bytes());
+    }
+
+    fn byte_len(&self) -> usize {
+        3 * std::mem::size_of::<f64>()
+    }
+}
+
+impl From<[f64; 3]> for CartesianVector {
+    fn from(components: [f64; 3]) -> CartesianVector {
+        CartesianVector {
+            x: components[0],
+            y: components[1],
+            z: components[2],
+        }
+    }
+}
+
+impl From<crate::vectors::vector3d::Vector3D> for CartesianVector {
+    fn from(v: crate::vectors::vector3d::Vector3D) -> CartesianVector {
+        CartesianVector::new(v.element(0), v.element(1), v.element(2))
+    }
+}
+
+impl From<CartesianVector> for crate::vectors::vector3d::Vector3D {
+    fn from(v: CartesianVector) -> crate::vectors::vector3d::Vector3D {
+        crate::vectors::vector3d::Vector3D::new(v.x(), v.y(), v.z())
+    }
+}
+
+impl PartialEq for CartesianVector {
+    /// Compares two `CartesianVector`s component-wise within a small epsilon,
+    /// since floating-point arithmetic rarely produces bit-exact results.
+    fn eq(&self, other: &Self) -> bool {
+        (self.x - other.x).abs() < EPSILON
+            && (self.y - other.y).abs() < EPSILON
+            && (self.z - other.z).abs() < EPSILON
+    }
+}
+
+impl Add for CartesianVector {
+    type Output = CartesianVector;
+
+    fn add(self, other: CartesianVector) -> CartesianVector {
+        CartesianVector {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl AddAssign for CartesianVector {
+    fn add_assign(&mut self, other: CartesianVector) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for CartesianVector {
+    type Output = CartesianVector;
+
+    fn sub(self, other: CartesianVector) -> CartesianVector {
+        CartesianVector {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl SubAssign for CartesianVector {
+    fn sub_assign(&mut self, other: CartesianVector) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for CartesianVector {
+    type Output = CartesianVector;
+
+    fn neg(self) -> CartesianVector {
+        CartesianVector {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl Mul<f64> for CartesianVector {
+    type Output = CartesianVector;
+
+    fn mul(self, scalar: f64) -> CartesianVector {
+        CartesianVector {
+            x: self.x * scalar,
+            y: self.y * scalar,
+            z: self.z * scalar,
+        }
+    }
+}
+
+impl MulAssign<f64> for CartesianVector {
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = *self * scalar;
+    }
+}
+
+impl Mul<CartesianVector> for CartesianVector {
+    type Output = f64;
+
+    /// Defined as the dot product, matching `CartesianVector::dot`.
+    fn mul(self, other: CartesianVector) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
 }
 
 impl SphericalVector {
@@ -427,6 +658,127 @@ mod cartesian_vector_tests {
         assert_eq!(b.z, 6.0);
     }
 
+    #[test]
+    fn test_cartesian_vector_add_operator() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        let b = CartesianVector::new(4.0, 5.0, 6.0);
+        assert_eq!(a + b, CartesianVector::new(5.0, 7.0, 9.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_add_assign_operator() {
+        let mut a = CartesianVector::new(1.0, 2.0, 3.0);
+        a += CartesianVector::new(4.0, 5.0, 6.0);
+        assert_eq!(a, CartesianVector::new(5.0, 7.0, 9.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_sub_operator() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        let b = CartesianVector::new(4.0, 5.0, 6.0);
+        assert_eq!(a - b, CartesianVector::new(-3.0, -3.0, -3.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_sub_assign_operator() {
+        let mut a = CartesianVector::new(1.0, 2.0, 3.0);
+        a -= CartesianVector::new(4.0, 5.0, 6.0);
+        assert_eq!(a, CartesianVector::new(-3.0, -3.0, -3.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_neg_operator() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        assert_eq!(-a, CartesianVector::new(-1.0, -2.0, -3.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_mul_scalar_operator() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        assert_eq!(a * 2.0, CartesianVector::new(2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_mul_assign_scalar_operator() {
+        let mut a = CartesianVector::new(1.0, 2.0, 3.0);
+        a *= 2.0;
+        assert_eq!(a, CartesianVector::new(2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_mul_vector_operator() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        let b = CartesianVector::new(4.0, 5.0, 6.0);
+        assert_eq!(a * b, 32.0);
+    }
+
+    #[test]
+    fn test_cartesian_vector_partial_eq() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        let b = CartesianVector::new(1.0 + 1e-12, 2.0, 3.0);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_cartesian_vector_project_on() {
+        let a = CartesianVector::new(1.0, 1.0, 0.0);
+        let b = CartesianVector::x_axis();
+        assert_eq!(a.project_on(b), CartesianVector::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_reject_from() {
+        let a = CartesianVector::new(1.0, 1.0, 0.0);
+        let b = CartesianVector::x_axis();
+        assert_eq!(a.reject_from(b), CartesianVector::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_angle_between() {
+        let a = CartesianVector::x_axis();
+        let b = CartesianVector::y_axis();
+        assert_eq!(a.angle_between(b), std::f64::consts::PI / 2.0);
+    }
+
+    #[test]
+    fn test_cartesian_vector_angle_between_identical() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        assert_eq!(a.angle_between(a), 0.0);
+    }
+
+    #[test]
+    fn test_cartesian_vector_reflect() {
+        let a = CartesianVector::new(1.0, -1.0, 0.0);
+        let normal = CartesianVector::y_axis();
+        assert_eq!(a.reflect(normal), CartesianVector::new(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_as_array() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        assert_eq!(a.as_array(), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_cartesian_vector_from_array() {
+        let a = CartesianVector::from([1.0, 2.0, 3.0]);
+        assert_eq!(a, CartesianVector::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_cartesian_vector_write_bytes_round_trip() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        let mut buffer = vec![0u8; a.byte_len()];
+        a.write_bytes(&mut buffer);
+        assert_eq!(CartesianVector::from_bytes(&buffer), a);
+    }
+
+    #[test]
+    fn test_cartesian_vector_byte_len() {
+        let a = CartesianVector::new(1.0, 2.0, 3.0);
+        assert_eq!(a.byte_len(), 24);
+    }
+
     #[test]
     fn test_cartesian_vector_cross() {
         let a = CartesianVector::new(1.0, 2.0, 3.0);
@@ -0,0 +1,268 @@
+//! Module for unit-tagged vector/point types with compile-time coordinate-space safety
+//!
+//! `CartesianVector` and `Vector3D` happily add a vector in world space to one
+//! in camera space; nothing in the type system stops that mistake. Borrowing
+//! euclid's design, [`TaggedVector3D`] and [`Point3D`] here carry a zero-sized
+//! `PhantomData<Unit>` marker so two vectors tagged with different `Unit`
+//! types simply don't implement `Add`/`Sub` against each other. The marker
+//! carries no data, so the impls below are written by hand instead of
+//! derived, to avoid requiring `Unit: Clone`/`Unit: Debug` at every call site.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A vector in the coordinate space tagged by `Unit`.
+pub struct TaggedVector3D<Unit> {
+    x: f64,
+    y: f64,
+    z: f64,
+    _unit: PhantomData<Unit>,
+}
+
+/// A point in the coordinate space tagged by `Unit`.
+pub struct Point3D<Unit> {
+    x: f64,
+    y: f64,
+    z: f64,
+    _unit: PhantomData<Unit>,
+}
+
+impl<Unit> TaggedVector3D<Unit> {
+    /// Creates a new TaggedVector3D tagged with `Unit`.
+    pub fn new(x: f64, y: f64, z: f64) -> TaggedVector3D<Unit> {
+        TaggedVector3D {
+            x,
+            y,
+            z,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns the x component.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the y component.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Returns the z component.
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    /// Calculates the magnitude (length) of the vector.
+    pub fn magnitude(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Calculates the dot product of this vector and another vector tagged
+    /// with the same `Unit`.
+    pub fn dot(&self, other: &TaggedVector3D<Unit>) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Calculates the cross product of this vector and another vector
+    /// tagged with the same `Unit`.
+    pub fn cross(&self, other: &TaggedVector3D<Unit>) -> TaggedVector3D<Unit> {
+        TaggedVector3D::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Reinterprets this vector as belonging to `NewUnit`, without touching
+    /// its components. An explicit escape hatch for the rare case where
+    /// crossing coordinate spaces really is intended.
+    pub fn cast_unit<NewUnit>(self) -> TaggedVector3D<NewUnit> {
+        TaggedVector3D::new(self.x, self.y, self.z)
+    }
+}
+
+impl<Unit> Point3D<Unit> {
+    /// Creates a new Point3D tagged with `Unit`.
+    pub fn new(x: f64, y: f64, z: f64) -> Point3D<Unit> {
+        Point3D {
+            x,
+            y,
+            z,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns the x component.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the y component.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Returns the z component.
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    /// Reinterprets this point as belonging to `NewUnit`, without touching
+    /// its components. An explicit escape hatch for the rare case where
+    /// crossing coordinate spaces really is intended.
+    pub fn cast_unit<NewUnit>(self) -> Point3D<NewUnit> {
+        Point3D::new(self.x, self.y, self.z)
+    }
+}
+
+impl<Unit> Add<TaggedVector3D<Unit>> for TaggedVector3D<Unit> {
+    type Output = TaggedVector3D<Unit>;
+
+    fn add(self, other: TaggedVector3D<Unit>) -> TaggedVector3D<Unit> {
+        TaggedVector3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<Unit> Sub<TaggedVector3D<Unit>> for TaggedVector3D<Unit> {
+    type Output = TaggedVector3D<Unit>;
+
+    fn sub(self, other: TaggedVector3D<Unit>) -> TaggedVector3D<Unit> {
+        TaggedVector3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<Unit> Mul<f64> for TaggedVector3D<Unit> {
+    type Output = TaggedVector3D<Unit>;
+
+    fn mul(self, scalar: f64) -> TaggedVector3D<Unit> {
+        TaggedVector3D::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl<Unit> Neg for TaggedVector3D<Unit> {
+    type Output = TaggedVector3D<Unit>;
+
+    fn neg(self) -> TaggedVector3D<Unit> {
+        TaggedVector3D::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<Unit> Add<TaggedVector3D<Unit>> for Point3D<Unit> {
+    type Output = Point3D<Unit>;
+
+    fn add(self, other: TaggedVector3D<Unit>) -> Point3D<Unit> {
+        Point3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<Unit> Sub<TaggedVector3D<Unit>> for Point3D<Unit> {
+    type Output = Point3D<Unit>;
+
+    fn sub(self, other: TaggedVector3D<Unit>) -> Point3D<Unit> {
+        Point3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<Unit> Sub<Point3D<Unit>> for Point3D<Unit> {
+    type Output = TaggedVector3D<Unit>;
+
+    fn sub(self, other: Point3D<Unit>) -> TaggedVector3D<Unit> {
+        TaggedVector3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<Unit> Clone for TaggedVector3D<Unit> {
+    fn clone(&self) -> TaggedVector3D<Unit> {
+        *self
+    }
+}
+
+impl<Unit> Copy for TaggedVector3D<Unit> {}
+
+impl<Unit> PartialEq for TaggedVector3D<Unit> {
+    fn eq(&self, other: &TaggedVector3D<Unit>) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+
+impl<Unit> fmt::Debug for TaggedVector3D<Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TaggedVector3D")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
+    }
+}
+
+impl<Unit> Clone for Point3D<Unit> {
+    fn clone(&self) -> Point3D<Unit> {
+        *self
+    }
+}
+
+impl<Unit> Copy for Point3D<Unit> {}
+
+impl<Unit> PartialEq for Point3D<Unit> {
+    fn eq(&self, other: &Point3D<Unit>) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+
+impl<Unit> fmt::Debug for Point3D<Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Point3D")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct WorldSpace;
+    struct CameraSpace;
+
+    #[test]
+    fn test_vector_add() {
+        let a: TaggedVector3D<WorldSpace> = TaggedVector3D::new(1.0, 2.0, 3.0);
+        let b: TaggedVector3D<WorldSpace> = TaggedVector3D::new(4.0, 5.0, 6.0);
+        assert_eq!(a + b, TaggedVector3D::new(5.0, 7.0, 9.0));
+    }
+
+    #[test]
+    fn test_vector_cross() {
+        let a: TaggedVector3D<WorldSpace> = TaggedVector3D::new(1.0, 0.0, 0.0);
+        let b: TaggedVector3D<WorldSpace> = TaggedVector3D::new(0.0, 1.0, 0.0);
+        assert_eq!(a.cross(&b), TaggedVector3D::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_point_minus_point_is_vector() {
+        let a: Point3D<WorldSpace> = Point3D::new(4.0, 5.0, 6.0);
+        let b: Point3D<WorldSpace> = Point3D::new(1.0, 2.0, 3.0);
+        let displacement: TaggedVector3D<WorldSpace> = a - b;
+        assert_eq!(displacement, TaggedVector3D::new(3.0, 3.0, 3.0));
+    }
+
+    #[test]
+    fn test_point_plus_vector() {
+        let p: Point3D<WorldSpace> = Point3D::new(1.0, 2.0, 3.0);
+        let v: TaggedVector3D<WorldSpace> = TaggedVector3D::new(1.0, 1.0, 1.0);
+        assert_eq!(p + v, Point3D::new(2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn test_cast_unit() {
+        let world: TaggedVector3D<WorldSpace> = TaggedVector3D::new(1.0, 2.0, 3.0);
+        let camera: TaggedVector3D<CameraSpace> = world.cast_unit();
+        assert_eq!(camera.x(), 1.0);
+        assert_eq!(camera.y(), 2.0);
+        assert_eq!(camera.z(), 3.0);
+    }
+}
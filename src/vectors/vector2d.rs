@@ -0,0 +1,320 @@
+use crate::bytes::{copy_repr_c_bytes, AsBytes};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Represents a 2D vector with x and y components.
+///
+/// The free functions in `vector_functions::vector_2d_functions` operate on
+/// raw `[f64; 2]` arrays, which can't carry `std::ops` impls because of
+/// Rust's orphan rules; `Vector2D` is the operator-overloaded counterpart,
+/// following the same pattern as `Vector3D`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub struct Vector2D {
+    x: f64,
+    y: f64,
+}
+
+impl Vector2D {
+    /// Creates a new Vector2D with the given x and y components.
+    pub fn new(x: f64, y: f64) -> Vector2D {
+        Vector2D { x, y }
+    }
+
+    /// Calculates the magnitude (length) of the vector.
+    pub fn magnitude(&self) -> f64 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    /// Calculates the dot product of this vector and another vector.
+    pub fn dot(&self, other: &Vector2D) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Adds this vector to another vector.
+    ///
+    /// Thin wrapper over `Add` kept for backwards compatibility; prefer `self + other`.
+    pub fn plus(&self, other: &Vector2D) -> Vector2D {
+        self + other
+    }
+
+    /// Subtracts another vector from this vector.
+    ///
+    /// Thin wrapper over `Sub` kept for backwards compatibility; prefer `self - other`.
+    pub fn minus(&self, other: &Vector2D) -> Vector2D {
+        self - other
+    }
+
+    /// Multiplies this vector by a scalar.
+    ///
+    /// Thin wrapper over `Mul<f64>` kept for backwards compatibility; prefer `self * scalar`.
+    pub fn times(&self, scalar: f64) -> Vector2D {
+        self * scalar
+    }
+
+    /// Gets the element at the given index.
+    pub fn element(&self, index: usize) -> f64 {
+        match index {
+            0 => self.x,
+            1 => self.y,
+            _ => panic!("Invalid index"),
+        }
+    }
+}
+
+impl Add<&Vector2D> for &Vector2D {
+    type Output = Vector2D;
+
+    fn add(self, other: &Vector2D) -> Vector2D {
+        Vector2D {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Add<Vector2D> for Vector2D {
+    type Output = Vector2D;
+
+    fn add(self, other: Vector2D) -> Vector2D {
+        &self + &other
+    }
+}
+
+impl Add<&Vector2D> for Vector2D {
+    type Output = Vector2D;
+
+    // Dropping the `&` here would re-resolve to this same impl instead of
+    // `Add<&Vector2D> for &Vector2D`, causing infinite recursion, so clippy's
+    // op_ref suggestion doesn't apply.
+    #[allow(clippy::op_ref)]
+    fn add(self, other: &Vector2D) -> Vector2D {
+        &self + other
+    }
+}
+
+impl Add<Vector2D> for &Vector2D {
+    type Output = Vector2D;
+
+    #[allow(clippy::op_ref)]
+    fn add(self, other: Vector2D) -> Vector2D {
+        self + &other
+    }
+}
+
+impl AddAssign for Vector2D {
+    fn add_assign(&mut self, other: Vector2D) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<&Vector2D> for &Vector2D {
+    type Output = Vector2D;
+
+    fn sub(self, other: &Vector2D) -> Vector2D {
+        Vector2D {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl Sub<Vector2D> for Vector2D {
+    type Output = Vector2D;
+
+    fn sub(self, other: Vector2D) -> Vector2D {
+        &self - &other
+    }
+}
+
+impl Sub<&Vector2D> for Vector2D {
+    type Output = Vector2D;
+
+    // See the matching note on `Add<&Vector2D> for Vector2D` above.
+    #[allow(clippy::op_ref)]
+    fn sub(self, other: &Vector2D) -> Vector2D {
+        &self - other
+    }
+}
+
+impl Sub<Vector2D> for &Vector2D {
+    type Output = Vector2D;
+
+    #[allow(clippy::op_ref)]
+    fn sub(self, other: Vector2D) -> Vector2D {
+        self - &other
+    }
+}
+
+impl SubAssign for Vector2D {
+    fn sub_assign(&mut self, other: Vector2D) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<f64> for &Vector2D {
+    type Output = Vector2D;
+
+    fn mul(self, scalar: f64) -> Vector2D {
+        Vector2D {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+impl Mul<f64> for Vector2D {
+    type Output = Vector2D;
+
+    // See the matching note on `Add<&Vector2D> for Vector2D` above.
+    #[allow(clippy::op_ref)]
+    fn mul(self, scalar: f64) -> Vector2D {
+        &self * scalar
+    }
+}
+
+impl MulAssign<f64> for Vector2D {
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = *self * scalar;
+    }
+}
+
+impl AsBytes for Vector2D {
+    fn write_bytes(&self, buffer: &mut [u8]) {
+        copy_repr_c_bytes(self, buffer);
+    }
+
+    fn byte_len(&self) -> usize {
+        std::mem::size_of::<Vector2D>()
+    }
+}
+
+impl Neg for &Vector2D {
+    type Output = Vector2D;
+
+    fn neg(self) -> Vector2D {
+        Vector2D {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Neg for Vector2D {
+    type Output = Vector2D;
+
+    fn neg(self) -> Vector2D {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_new() {
+        let vector = Vector2D::new(1.0, 2.0);
+        assert_eq!(vector.x, 1.0);
+        assert_eq!(vector.y, 2.0);
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let vector = Vector2D::new(3.0, 4.0);
+        assert_approx_eq!(vector.magnitude(), 5.0, 1e-12);
+    }
+
+    #[test]
+    fn test_dot() {
+        let vector1 = Vector2D::new(1.0, 2.0);
+        let vector2 = Vector2D::new(3.0, 4.0);
+        assert_eq!(vector1.dot(&vector2), 11.0);
+    }
+
+    #[test]
+    fn test_plus() {
+        let vector1 = Vector2D::new(1.0, 2.0);
+        let vector2 = Vector2D::new(3.0, 4.0);
+        assert_eq!(vector1.plus(&vector2), Vector2D::new(4.0, 6.0));
+    }
+
+    #[test]
+    fn test_minus() {
+        let vector1 = Vector2D::new(1.0, 2.0);
+        let vector2 = Vector2D::new(3.0, 4.0);
+        assert_eq!(vector1.minus(&vector2), Vector2D::new(-2.0, -2.0));
+    }
+
+    #[test]
+    fn test_element() {
+        let vector = Vector2D::new(1.0, 2.0);
+        assert_eq!(vector.element(0), 1.0);
+        assert_eq!(vector.element(1), 2.0);
+    }
+
+    #[test]
+    fn test_add_operator() {
+        let vector1 = Vector2D::new(1.0, 2.0);
+        let vector2 = Vector2D::new(3.0, 4.0);
+        let result = vector1 + vector2;
+        assert_eq!(result, Vector2D::new(4.0, 6.0));
+        assert_eq!(vector1 + vector2, result);
+    }
+
+    #[test]
+    fn test_sub_operator() {
+        let vector1 = Vector2D::new(1.0, 2.0);
+        let vector2 = Vector2D::new(3.0, 4.0);
+        let result = vector1 - vector2;
+        assert_eq!(result, Vector2D::new(-2.0, -2.0));
+        assert_eq!(vector1 - vector2, result);
+    }
+
+    #[test]
+    fn test_mul_scalar_operator() {
+        let vector = Vector2D::new(1.0, 2.0);
+        let result = vector * 2.0;
+        assert_eq!(result, Vector2D::new(2.0, 4.0));
+        assert_eq!(&vector * 2.0, result);
+    }
+
+    #[test]
+    fn test_neg_operator() {
+        let vector = Vector2D::new(1.0, 2.0);
+        let result = -vector;
+        assert_eq!(result, Vector2D::new(-1.0, -2.0));
+        assert_eq!(-&vector, result);
+    }
+
+    #[test]
+    fn test_add_assign_operator() {
+        let mut vector = Vector2D::new(1.0, 2.0);
+        vector += Vector2D::new(3.0, 4.0);
+        assert_eq!(vector, Vector2D::new(4.0, 6.0));
+    }
+
+    #[test]
+    fn test_sub_assign_operator() {
+        let mut vector = Vector2D::new(1.0, 2.0);
+        vector -= Vector2D::new(3.0, 4.0);
+        assert_eq!(vector, Vector2D::new(-2.0, -2.0));
+    }
+
+    #[test]
+    fn test_mul_assign_operator() {
+        let mut vector = Vector2D::new(1.0, 2.0);
+        vector *= 2.0;
+        assert_eq!(vector, Vector2D::new(2.0, 4.0));
+    }
+
+    #[test]
+    fn test_bytes_write_bytes() {
+        let vector = Vector2D::new(1.0, 2.0);
+        assert_eq!(vector.byte_len(), 16);
+        let mut buffer = [0u8; 16];
+        vector.write_bytes(&mut buffer);
+        assert_eq!(&buffer[0..8], &1.0f64.to_le_bytes());
+        assert_eq!(&buffer[8..16], &2.0f64.to_le_bytes());
+    }
+}
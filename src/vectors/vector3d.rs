@@ -1,5 +1,9 @@
+use crate::bytes::{copy_repr_c_bytes, AsBytes};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
 /// Represents a 3D vector with x, y, and z components.
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
 pub struct Vector3D {
     x: f64,
     y: f64,
@@ -33,22 +37,25 @@ impl Vector3D {
 
     /// Adds this vector to another vector.
     /// Returns a new vector with the result.
+    ///
+    /// Thin wrapper over `Add` kept for backwards compatibility; prefer `self + other`.
     pub fn plus(&self, other: &Vector3D) -> Vector3D {
-        Vector3D {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-        }
+        self + other
     }
 
     /// Subtracts another vector from this vector.
     /// Returns a new vector with the result.
+    ///
+    /// Thin wrapper over `Sub` kept for backwards compatibility; prefer `self - other`.
     pub fn minus(&self, other: &Vector3D) -> Vector3D {
-        Vector3D {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-        }
+        self - other
+    }
+
+    /// Multiplies this vector by a scalar.
+    ///
+    /// Thin wrapper over `Mul<f64>` kept for backwards compatibility; prefer `self * scalar`.
+    pub fn times(&self, scalar: f64) -> Vector3D {
+        self * scalar
     }
 
     /// Gets the element at the given index.
@@ -62,6 +69,156 @@ impl Vector3D {
     }
 }
 
+impl Add<&Vector3D> for &Vector3D {
+    type Output = Vector3D;
+
+    fn add(self, other: &Vector3D) -> Vector3D {
+        Vector3D {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Add<Vector3D> for Vector3D {
+    type Output = Vector3D;
+
+    fn add(self, other: Vector3D) -> Vector3D {
+        &self + &other
+    }
+}
+
+impl Add<&Vector3D> for Vector3D {
+    type Output = Vector3D;
+
+    // Dropping the `&` here would re-resolve to this same impl instead of
+    // `Add<&Vector3D> for &Vector3D`, causing infinite recursion, so clippy's
+    // op_ref suggestion doesn't apply.
+    #[allow(clippy::op_ref)]
+    fn add(self, other: &Vector3D) -> Vector3D {
+        &self + other
+    }
+}
+
+impl Add<Vector3D> for &Vector3D {
+    type Output = Vector3D;
+
+    #[allow(clippy::op_ref)]
+    fn add(self, other: Vector3D) -> Vector3D {
+        self + &other
+    }
+}
+
+impl AddAssign for Vector3D {
+    fn add_assign(&mut self, other: Vector3D) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<&Vector3D> for &Vector3D {
+    type Output = Vector3D;
+
+    fn sub(self, other: &Vector3D) -> Vector3D {
+        Vector3D {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl Sub<Vector3D> for Vector3D {
+    type Output = Vector3D;
+
+    fn sub(self, other: Vector3D) -> Vector3D {
+        &self - &other
+    }
+}
+
+impl Sub<&Vector3D> for Vector3D {
+    type Output = Vector3D;
+
+    // See the matching note on `Add<&Vector3D> for Vector3D` above.
+    #[allow(clippy::op_ref)]
+    fn sub(self, other: &Vector3D) -> Vector3D {
+        &self - other
+    }
+}
+
+impl Sub<Vector3D> for &Vector3D {
+    type Output = Vector3D;
+
+    #[allow(clippy::op_ref)]
+    fn sub(self, other: Vector3D) -> Vector3D {
+        self - &other
+    }
+}
+
+impl SubAssign for Vector3D {
+    fn sub_assign(&mut self, other: Vector3D) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<f64> for &Vector3D {
+    type Output = Vector3D;
+
+    fn mul(self, scalar: f64) -> Vector3D {
+        Vector3D {
+            x: self.x * scalar,
+            y: self.y * scalar,
+            z: self.z * scalar,
+        }
+    }
+}
+
+impl Mul<f64> for Vector3D {
+    type Output = Vector3D;
+
+    // See the matching note on `Add<&Vector3D> for Vector3D` above.
+    #[allow(clippy::op_ref)]
+    fn mul(self, scalar: f64) -> Vector3D {
+        &self * scalar
+    }
+}
+
+impl MulAssign<f64> for Vector3D {
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = *self * scalar;
+    }
+}
+
+impl AsBytes for Vector3D {
+    fn write_bytes(&self, buffer: &mut [u8]) {
+        copy_repr_c_bytes(self, buffer);
+    }
+
+    fn byte_len(&self) -> usize {
+        std::mem::size_of::<Vector3D>()
+    }
+}
+
+impl Neg for &Vector3D {
+    type Output = Vector3D;
+
+    fn neg(self) -> Vector3D {
+        Vector3D {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl Neg for Vector3D {
+    type Output = Vector3D;
+
+    fn neg(self) -> Vector3D {
+        -&self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +281,70 @@ mod tests {
         assert_eq!(vector.element(1), 2.0);
         assert_eq!(vector.element(2), 3.0);
     }
+
+    #[test]
+    fn test_add_operator() {
+        let vector1 = Vector3D::new(1.0, 2.0, 3.0);
+        let vector2 = Vector3D::new(4.0, 5.0, 6.0);
+        let result = vector1 + vector2;
+        assert_eq!(result, Vector3D::new(5.0, 7.0, 9.0));
+        assert_eq!(vector1 + vector2, result);
+    }
+
+    #[test]
+    fn test_sub_operator() {
+        let vector1 = Vector3D::new(1.0, 2.0, 3.0);
+        let vector2 = Vector3D::new(4.0, 5.0, 6.0);
+        let result = vector1 - vector2;
+        assert_eq!(result, Vector3D::new(-3.0, -3.0, -3.0));
+        assert_eq!(vector1 - vector2, result);
+    }
+
+    #[test]
+    fn test_mul_scalar_operator() {
+        let vector = Vector3D::new(1.0, 2.0, 3.0);
+        let result = vector * 2.0;
+        assert_eq!(result, Vector3D::new(2.0, 4.0, 6.0));
+        assert_eq!(&vector * 2.0, result);
+    }
+
+    #[test]
+    fn test_neg_operator() {
+        let vector = Vector3D::new(1.0, 2.0, 3.0);
+        let result = -vector;
+        assert_eq!(result, Vector3D::new(-1.0, -2.0, -3.0));
+        assert_eq!(-&vector, result);
+    }
+
+    #[test]
+    fn test_add_assign_operator() {
+        let mut vector = Vector3D::new(1.0, 2.0, 3.0);
+        vector += Vector3D::new(4.0, 5.0, 6.0);
+        assert_eq!(vector, Vector3D::new(5.0, 7.0, 9.0));
+    }
+
+    #[test]
+    fn test_sub_assign_operator() {
+        let mut vector = Vector3D::new(1.0, 2.0, 3.0);
+        vector -= Vector3D::new(4.0, 5.0, 6.0);
+        assert_eq!(vector, Vector3D::new(-3.0, -3.0, -3.0));
+    }
+
+    #[test]
+    fn test_mul_assign_operator() {
+        let mut vector = Vector3D::new(1.0, 2.0, 3.0);
+        vector *= 2.0;
+        assert_eq!(vector, Vector3D::new(2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_bytes_write_bytes() {
+        let vector = Vector3D::new(1.0, 2.0, 3.0);
+        assert_eq!(vector.byte_len(), 24);
+        let mut buffer = [0u8; 24];
+        vector.write_bytes(&mut buffer);
+        assert_eq!(&buffer[0..8], &1.0f64.to_le_bytes());
+        assert_eq!(&buffer[8..16], &2.0f64.to_le_bytes());
+        assert_eq!(&buffer[16..24], &3.0f64.to_le_bytes());
+    }
 }